@@ -1,12 +1,16 @@
-use crate::commands::scan::Diag;
+use crate::commands::scan::{Diag, FlowSpan};
 use crate::errors::{NyxError, NyxResult};
+use crate::utils::config::AnalysisMode;
 use crate::utils::ext::lowercase_ext;
 use crate::utils::{Config, query_cache};
 use std::cell::RefCell;
 use std::path::Path;
 use tree_sitter::{Language, QueryCursor, StreamingIterator};
-use crate::cfg::{analyse_function, build_cfg};
+use crate::cfg::{build_cfg, Cfg, NodeInfo};
+use crate::labels::{self, Cap, DataLabel, Kind};
+use crate::taint::analyse_file;
 use crate::patterns::Severity;
+use petgraph::graph::NodeIndex;
 
 thread_local! {
     static PARSER: RefCell<tree_sitter::Parser> = RefCell::new(tree_sitter::Parser::new());
@@ -22,83 +26,296 @@ fn byte_offset_to_point(tree: &tree_sitter::Tree, byte: usize) -> tree_sitter::P
     .unwrap_or_else(|| tree_sitter::Point { row: 0, column: 0 })
 }
 
+/// Pick a rule id for a confirmed taint finding from the sink node's own
+/// capability bits, so e.g. a shell sink reads as `taint_cmd_injection`
+/// rather than the generic fallback — falling back to that generic id for
+/// sink kinds this table doesn't know about yet.
+fn taint_rule_id(sink: &NodeInfo) -> &'static str {
+    match sink.label {
+        Some(DataLabel::Sink(bits)) if bits.contains(Cap::SHELL_ESCAPE) => "taint_cmd_injection",
+        Some(DataLabel::Sink(bits)) if bits.contains(Cap::HTML_ESCAPE) => "taint_xss",
+        _ => "taint-unsanitised-flow",
+    }
+}
+
+/// Compiler-error-style title for a confirmed taint finding, keyed off the
+/// sink's own capability bits the same way [`taint_rule_id`] is.
+fn taint_flow_title(sink: &NodeInfo) -> &'static str {
+    match sink.label {
+        Some(DataLabel::Sink(bits)) if bits.contains(Cap::SHELL_ESCAPE) => {
+            "untrusted data reaches shell sink"
+        }
+        Some(DataLabel::Sink(bits)) if bits.contains(Cap::HTML_ESCAPE) => {
+            "untrusted data reaches XSS sink"
+        }
+        _ => "untrusted data reaches sink",
+    }
+}
+
+/// Turns a source→…→sink node chain from [`analyse_file`] into the labeled
+/// spans `Diag::spans` expects: one primary label at the sink ("tainted
+/// value used here"), one at the source ("untrusted data enters here"), and
+/// a generic secondary label at everything in between.
+fn taint_flow_spans(tree: &tree_sitter::Tree, cfg: &Cfg, sink_idx: NodeIndex, path_nodes: &[NodeIndex]) -> Vec<FlowSpan> {
+    path_nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &idx)| {
+            let n = &cfg[idx];
+            let start = byte_offset_to_point(tree, n.span.0);
+            let end = byte_offset_to_point(tree, n.span.1);
+            let (label, primary) = if idx == sink_idx {
+                ("tainted value used here", true)
+            } else if i == 0 {
+                ("untrusted data enters here", false)
+            } else {
+                ("value flows through here", false)
+            };
+            FlowSpan {
+                label: label.to_owned(),
+                primary,
+                line: start.row + 1,
+                col: start.column + 1,
+                end_line: Some(end.row + 1),
+                end_col: Some(end.column + 1),
+            }
+        })
+        .collect()
+}
+
+/// Maps a lowercase file extension (see [`lowercase_ext`]) to its
+/// tree-sitter grammar and the short language slug used elsewhere (pattern
+/// registries, CFG building, function extraction).
+pub(crate) fn detect_language(ext: Option<&str>) -> Option<(Language, &'static str)> {
+    match ext {
+        Some("rs") => Some((Language::from(tree_sitter_rust::LANGUAGE), "rust")),
+        Some("c") => Some((Language::from(tree_sitter_c::LANGUAGE), "c")),
+        Some("cpp") => Some((Language::from(tree_sitter_cpp::LANGUAGE), "cpp")),
+        Some("java") => Some((Language::from(tree_sitter_java::LANGUAGE), "java")),
+        Some("go") => Some((Language::from(tree_sitter_go::LANGUAGE), "go")),
+        Some("php") => Some((Language::from(tree_sitter_php::LANGUAGE_PHP), "php")),
+        Some("py") => Some((Language::from(tree_sitter_python::LANGUAGE), "python")),
+        Some("ts") => Some((
+            Language::from(tree_sitter_typescript::LANGUAGE_TYPESCRIPT),
+            "typescript",
+        )),
+        Some("js") => Some((
+            Language::from(tree_sitter_javascript::LANGUAGE),
+            "javascript",
+        )),
+        Some("rb") => Some((Language::from(tree_sitter_ruby::LANGUAGE), "ruby")),
+        _ => None,
+    }
+}
+
+/// Maps a language slug (as used by `patterns::load` and `UserRule::lang`)
+/// to its tree-sitter grammar. A sibling of [`detect_language`], which maps
+/// file extensions instead — needed separately because user-supplied rules
+/// name a language, not a file.
+pub(crate) fn language_for_slug(slug: &str) -> Option<Language> {
+    match slug {
+        "rust" => Some(Language::from(tree_sitter_rust::LANGUAGE)),
+        "c" => Some(Language::from(tree_sitter_c::LANGUAGE)),
+        "cpp" | "c++" => Some(Language::from(tree_sitter_cpp::LANGUAGE)),
+        "java" => Some(Language::from(tree_sitter_java::LANGUAGE)),
+        "go" => Some(Language::from(tree_sitter_go::LANGUAGE)),
+        "php" => Some(Language::from(tree_sitter_php::LANGUAGE_PHP)),
+        "python" | "py" => Some(Language::from(tree_sitter_python::LANGUAGE)),
+        "typescript" | "ts" | "tsx" => {
+            Some(Language::from(tree_sitter_typescript::LANGUAGE_TYPESCRIPT))
+        }
+        "javascript" | "js" => Some(Language::from(tree_sitter_javascript::LANGUAGE)),
+        "ruby" | "rb" => Some(Language::from(tree_sitter_ruby::LANGUAGE)),
+        _ => None,
+    }
+}
+
+/// A `// nyx:ignore[rule_id]` (or bare `// nyx:ignore`) directive found in a
+/// `Kind::Trivia`-classified comment node. Suppresses any finding whose own
+/// line is `line` (a trailing comment on the matched code) or `line + 1` (a
+/// comment on the line immediately above); a missing `rule_id` suppresses
+/// every rule at that line.
+struct Suppression {
+    line: usize,
+    rule_id: Option<String>,
+}
+
+/// Walk every node in `tree`, collecting a [`Suppression`] for each comment
+/// node (classified `Trivia` by [`labels::lookup`], or any node whose kind
+/// names a comment) containing an `nyx:ignore` directive. This is a plain
+/// tree-sitter DFS rather than a query so it works uniformly across every
+/// language regardless of whether that language's `Cap`/`DataLabel`
+/// classifier table is populated.
+fn collect_suppressions(tree: &tree_sitter::Tree, bytes: &[u8], lang_slug: &str) -> Vec<Suppression> {
+    const DIRECTIVE: &str = "nyx:ignore";
+    let mut out = Vec::new();
+    let mut cursor = tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        if labels::lookup(lang_slug, node.kind()) == Kind::Trivia || node.kind().contains("comment") {
+            if let Ok(text) = node.utf8_text(bytes) {
+                if let Some(rest) = text.find(DIRECTIVE).map(|i| &text[i + DIRECTIVE.len()..]) {
+                    let rule_id = rest
+                        .trim_start()
+                        .strip_prefix('[')
+                        .and_then(|s| s.split(']').next())
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_owned);
+                    out.push(Suppression {
+                        line: node.start_position().row + 1,
+                        rule_id,
+                    });
+                }
+            }
+        }
+
+        if cursor.goto_first_child() {
+            continue;
+        }
+        loop {
+            if cursor.goto_next_sibling() {
+                continue 'walk;
+            }
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+    out
+}
+
+/// Whether any collected [`Suppression`] covers `(line, rule_id)`.
+fn is_suppressed(line: usize, rule_id: &str, suppressions: &[Suppression]) -> bool {
+    suppressions
+        .iter()
+        .any(|s| (s.line == line || s.line + 1 == line) && s.rule_id.as_deref().map_or(true, |id| id == rule_id))
+}
+
 pub(crate) fn run_rules_on_file(path: &Path, cfg: &Config) -> NyxResult<Vec<Diag>> {
     tracing::debug!("Running rules on: {}", path.display());
     let bytes = std::fs::read(path)?;
+    run_rules_on_bytes(path, &bytes, cfg)
+}
 
+/// The actual analysis, shared by `run_rules_on_file` (reads `path` off
+/// disk) and the watch/LSP session in `crate::daemon` (hands in a buffer
+/// that may be ahead of what's saved, e.g. an editor's unsaved `didChange`
+/// content) — the only thing that ever needed to come from disk was the
+/// bytes themselves, everything downstream already only cares about `path`
+/// for labeling diagnostics. The thread-local `PARSER` above is the
+/// "session" a long-running caller reuses across many calls: it's already
+/// amortized per-thread, so a single-threaded watch loop or LSP event loop
+/// gets that reuse for free just by calling this repeatedly from the same
+/// thread instead of spawning one per file the way `scan_filesystem` does.
+pub(crate) fn run_rules_on_bytes(path: &Path, bytes: &[u8], cfg: &Config) -> NyxResult<Vec<Diag>> {
     // Fast binary-file guard (skip if >1% NULs)
     if bytes.iter().filter(|b| **b == 0).count() * 100 / bytes.len().max(1) > 1 {
         return Ok(vec![]);
     }
 
-    let (ts_lang, lang_slug) = match lowercase_ext(path) {
-        Some("rs") => (Language::from(tree_sitter_rust::LANGUAGE), "rust"),
-        Some("c") => (Language::from(tree_sitter_c::LANGUAGE), "c"),
-        Some("cpp") => (Language::from(tree_sitter_cpp::LANGUAGE), "cpp"),
-        Some("java") => (Language::from(tree_sitter_java::LANGUAGE), "java"),
-        Some("go") => (Language::from(tree_sitter_go::LANGUAGE), "go"),
-        Some("php") => (Language::from(tree_sitter_php::LANGUAGE_PHP), "php"),
-        Some("py") => (Language::from(tree_sitter_python::LANGUAGE), "python"),
-        Some("ts") => (
-            Language::from(tree_sitter_typescript::LANGUAGE_TYPESCRIPT),
-            "typescript",
-        ),
-        Some("js") => (
-            Language::from(tree_sitter_javascript::LANGUAGE),
-            "javascript",
-        ),
-        Some("rb") => (Language::from(tree_sitter_ruby::LANGUAGE), "ruby"),
-        _ => return Ok(vec![]),
+    let Some((ts_lang, lang_slug)) = detect_language(lowercase_ext(path)) else {
+        return Ok(vec![]);
     };
 
     let _tree = PARSER.with(|cell| {
         let mut parser = cell.borrow_mut();
         parser.set_language(&ts_lang)?;
         parser
-            .parse(&*bytes, None)
+            .parse(bytes, None)
             .ok_or_else(|| NyxError::Other("tree-sitter failed".into()))
     })?;
   
     let mut out = Vec::new();
-    let (cfg_graph, entry) = build_cfg(&_tree, &bytes, lang_slug);
-
-    for p in analyse_function(&cfg_graph, entry) {
-      let src_byte = cfg_graph[p.first().copied().unwrap()].span.0;
-      let point    = byte_offset_to_point(&_tree, src_byte);
-      
-      out.push(Diag {
-          path:     path.to_string_lossy().into_owned(),
-          line:     point.row + 1,     
-          col:      point.column + 1,
-          severity: Severity::High,              
-          id:       "taint-unsanitised-flow".into(),
-     });
-     }
-
-    let root = _tree.root_node();
-    
-    let compiled = query_cache::for_lang(lang_slug, ts_lang);
-    let mut cursor = QueryCursor::new();
-    
-    for cq in compiled.iter() {
-        if cfg.scanner.min_severity <= cq.meta.severity {
-            continue;
+
+    // `Full` runs both passes; `Taint` / `Ast` each run only their own.
+    if cfg.scanner.mode != AnalysisMode::Ast {
+        let (cfg_graph, entry, summaries) = build_cfg(&_tree, bytes, lang_slug);
+
+        for path_nodes in analyse_file(&cfg_graph, entry, &summaries) {
+            // `path_nodes` runs source → … → sink; report at the sink so the
+            // finding lands on the line that actually needs fixing.
+            let sink_idx = *path_nodes.last().unwrap();
+            let sink = &cfg_graph[sink_idx];
+            let point = byte_offset_to_point(&_tree, sink.span.0);
+            let end_point = byte_offset_to_point(&_tree, sink.span.1);
+
+            out.push(Diag {
+                path: path.to_string_lossy().into_owned(),
+                line: point.row + 1,
+                col: point.column + 1,
+                severity: Severity::High,
+                id: taint_rule_id(sink).to_owned(),
+                end_line: Some(end_point.row + 1),
+                end_col: Some(end_point.column + 1),
+                title: Some(taint_flow_title(sink).to_owned()),
+                spans: taint_flow_spans(&_tree, &cfg_graph, sink_idx, &path_nodes),
+                caps: match sink.label {
+                    Some(DataLabel::Sink(bits)) => bits,
+                    _ => Cap::empty(),
+                },
+            });
         }
-        let mut matches = cursor.matches(&cq.query, root, &*bytes);
-        while let Some(m) = matches.next() {
-            if let Some(cap) = m.captures.iter().find(|c| c.index == 0) {
-                let point = cap.node.start_position();
+
+        // Constant-propagation findings (out-of-bounds index, arithmetic
+        // overflow) don't need taint's source/sink labels, just the same
+        // CFG — but the AST-shape assumptions (`index_expression`, `: iN`
+        // type annotations, …) are Rust-specific, so this only runs there.
+        if lang_slug == "rust" {
+            for f in crate::constprop::analyse_constants(&_tree, &cfg_graph, bytes) {
+                let point = byte_offset_to_point(&_tree, f.span.0);
+                let end_point = byte_offset_to_point(&_tree, f.span.1);
                 out.push(Diag {
                     path: path.to_string_lossy().into_owned(),
                     line: point.row + 1,
                     col: point.column + 1,
-                    severity: cq.meta.severity,
-                    id: cq.meta.id.to_owned(),
+                    severity: Severity::High,
+                    id: f.id.to_owned(),
+                    end_line: Some(end_point.row + 1),
+                    end_col: Some(end_point.column + 1),
+                    title: None,
+                    spans: Vec::new(),
+                    caps: Cap::empty(),
                 });
             }
         }
     }
-  
+
+    if cfg.scanner.mode != AnalysisMode::Taint {
+        let root = _tree.root_node();
+
+        let compiled = query_cache::for_lang(lang_slug, ts_lang, &cfg.scanner.rules);
+        let mut cursor = QueryCursor::new();
+
+        for cq in compiled.iter() {
+            if cfg.scanner.min_severity <= cq.meta.severity {
+                continue;
+            }
+            let mut matches = cursor.matches(&cq.query, root, &*bytes);
+            while let Some(m) = matches.next() {
+                if let Some(cap) = m.captures.iter().find(|c| c.index == 0) {
+                    let point = cap.node.start_position();
+                    let end_point = cap.node.end_position();
+                    out.push(Diag {
+                        path: path.to_string_lossy().into_owned(),
+                        line: point.row + 1,
+                        col: point.column + 1,
+                        severity: cq.meta.severity,
+                        id: cq.meta.id.to_owned(),
+                        end_line: Some(end_point.row + 1),
+                        end_col: Some(end_point.column + 1),
+                        title: None,
+                        spans: Vec::new(),
+                        caps: Cap::empty(),
+                    });
+                }
+            }
+        }
+    }
+
+    let suppressions = collect_suppressions(&_tree, bytes, lang_slug);
+    out.retain(|d| !is_suppressed(d.line, &d.id, &suppressions));
+
     out.sort_by(|a, b| (a.line, a.col, &a.id, a.severity)
       .cmp(&(b.line, b.col, &b.id, b.severity)));
     out.dedup_by(|a, b| {
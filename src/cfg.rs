@@ -1,10 +1,11 @@
 use petgraph::algo::dominators::{Dominators, simple_fast};
 use petgraph::prelude::*;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 use tree_sitter::{Node, Tree};
 
 use crate::labels::{Cap, DataLabel, Kind, classify, lookup};
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 // WHAT WE STILL NEED TO DO:
 // todo: add the cap labels and remove the bit flags after each sanitizer, checking the bit flags with the sink
 //
@@ -24,7 +25,7 @@ use std::collections::{HashMap, HashSet};
 /// -------------------------------------------------------------------------
 ///  Public AST‑to‑CFG data structures
 /// -------------------------------------------------------------------------
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum StmtKind {
     Entry,
     Exit,
@@ -37,15 +38,16 @@ pub enum StmtKind {
     Call,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EdgeKind {
     Seq,   // ordinary fall‑through
     True,  // `cond == true` branch
     False, // `cond == false` branch
+    Case,  // one `match`/switch arm, from the scrutinee to the arm's first node
     Back,  // back‑edge that closes a loop
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct NodeInfo {
     pub kind: StmtKind,
     pub span: (usize, usize),     // byte offsets in the original file
@@ -53,10 +55,45 @@ pub struct NodeInfo {
     pub defines: Option<String>,  // variable written by this stmt
     pub uses: Vec<String>,        // variables read
     pub callee: Option<String>,
+    /// For a `Call` node: each argument's identifier, in call order, or
+    /// `None` for an argument that isn't a simple variable (a literal,
+    /// nested call, …) — positional, unlike `uses` (which flattens every
+    /// identifier in the whole expression, callee name included, and is
+    /// unordered w.r.t. which argument it came from). Empty for every other
+    /// node kind. Lets interprocedural taint match a call's arguments up
+    /// against the callee's per-parameter summary (see `FuncSummaries`).
+    pub call_args: Vec<Option<String>>,
 }
 
 pub type Cfg = Graph<NodeInfo, EdgeKind>;
-pub type FuncSummaries = HashMap<String, (NodeIndex, NodeIndex, Option<DataLabel>)>;
+
+/// Per-parameter interprocedural summary: the capability bits that reach a
+/// `Sink` call and/or the function's return value when *that one* parameter
+/// is assumed to carry every capability — the conservative seed needed to
+/// learn reachability without already knowing the real taint a caller would
+/// pass in. Computed by [`propagate_param`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParamSummary {
+    pub to_sink: Cap,
+    pub to_return: Cap,
+    /// Bits cleared off the parameter itself by the time every path through
+    /// the function reaches an exit — i.e. the function reassigns its
+    /// parameter (directly, or through a `&mut` alias) to a cleaned value,
+    /// the way `fn sanitize_in_place(s: &mut String) { *s = escape(s); }`
+    /// does. Only set for bits cleared on *every* exit path, same
+    /// must-reach conservatism as [`dominated_by_sanitizer`] — a helper that
+    /// sanitizes on just one branch shouldn't make a caller treat the
+    /// argument as clean afterwards.
+    pub sanitizes: Cap,
+}
+
+/// `(NodeIndex, NodeIndex, whole-function label, per-parameter summaries)`.
+/// Parameters are a `Vec` rather than a `HashMap` to keep their declaration
+/// order — callers match a call's positional `NodeInfo::call_args` up
+/// against this list by index, same as Rust itself matches arguments to
+/// parameters.
+pub type FuncSummaries =
+    HashMap<String, (NodeIndex, NodeIndex, Option<DataLabel>, Vec<(String, ParamSummary)>)>;
 
 // -------------------------------------------------------------------------
 //                      Utility helpers
@@ -107,6 +144,71 @@ fn first_call_ident<'a>(n: Node<'a>, lang: &str, code: &'a [u8]) -> Option<Strin
     None
 }
 
+/// Same search as [`first_call_ident`] (first call/method/macro among `n`'s
+/// immediate children, falling back to `n` itself if it already is one) but
+/// returns the matched node rather than just its callee text, so the caller
+/// can still reach its `arguments` field.
+fn first_call_node<'a>(n: Node<'a>, lang: &str) -> Option<Node<'a>> {
+    if matches!(
+        lookup(lang, n.kind()),
+        Kind::CallFn | Kind::CallMethod | Kind::CallMacro
+    ) {
+        return Some(n);
+    }
+    let mut cursor = n.walk();
+    n.children(&mut cursor)
+        .find(|c| matches!(lookup(lang, c.kind()), Kind::CallFn | Kind::CallMethod | Kind::CallMacro))
+}
+
+/// The call's own arguments, in order, as the first identifier found inside
+/// each one (`None` for an argument that isn't a simple variable) — used to
+/// line a call site's arguments up positionally against the callee's
+/// per-parameter summary (see [`ParamSummary`]).
+fn call_argument_idents(n: Node, lang: &str, code: &[u8]) -> Vec<Option<String>> {
+    let Some(call) = first_call_node(n, lang) else {
+        return Vec::new();
+    };
+    let Some(args) = call.child_by_field_name("arguments") else {
+        return Vec::new();
+    };
+    let mut cursor = args.walk();
+    args.named_children(&mut cursor)
+        .map(|arg| {
+            let mut tmp = Vec::new();
+            collect_idents(arg, code, &mut tmp);
+            tmp.into_iter().next()
+        })
+        .collect()
+}
+
+/// The operator token text of a `binary_expression`, e.g. `"&&"`. Tree-sitter
+/// gives every binary operator (`+`, `==`, `&&`, …) the same node kind, so
+/// telling short-circuit operators apart means reading the operator token
+/// itself rather than just classifying the node kind.
+pub(crate) fn binary_operator_text<'a>(n: Node<'a>, code: &'a [u8]) -> Option<String> {
+    if let Some(op) = n.child_by_field_name("operator") {
+        return text_of(op, code);
+    }
+    let mut cursor = n.walk();
+    n.children(&mut cursor)
+        .find(|c| matches!(c.kind(), "&&" | "||"))
+        .and_then(|c| text_of(c, code))
+}
+
+/// True for AST nodes `build_sub` turns into real branching CFG shapes:
+/// everything `Kind::If`/`Match`/loops/`Try` already cover, plus short-circuit
+/// `&&`/`||` — which, unlike those, doesn't get its own node kind and has to
+/// be recognised by operator text instead.
+fn is_control_construct(lang: &str, n: Node, code: &[u8]) -> bool {
+    match lookup(lang, n.kind()) {
+        Kind::InfiniteLoop | Kind::While | Kind::For | Kind::If | Kind::Match | Kind::Try => true,
+        _ => {
+            n.kind() == "binary_expression"
+                && matches!(binary_operator_text(n, code).as_deref(), Some("&&" | "||"))
+        }
+    }
+}
+
 /// Recursively collect every identifier that occurs inside `n`.
 fn collect_idents(n: Node, code: &[u8], out: &mut Vec<String>) {
     if n.kind() == "identifier" {
@@ -156,6 +258,42 @@ fn def_use(ast: Node, lang: &str, code: &[u8]) -> (Option<String>, Vec<String>)
             (defs, uses)
         }
 
+        // JS/Java `let x = val;` / `const x = val;` / `var x = val;` / `Type x = val;`
+        // wrap the actual binding in a nested declarator node (one per
+        // comma-separated name, `pattern`/`value`'s Rust-side role played by
+        // `name`/`value` instead) rather than carrying those fields
+        // themselves — so, unlike Rust's `let_declaration`, drill into the
+        // first declarator before reading them.
+        "lexical_declaration" | "variable_declaration" | "local_variable_declaration" => {
+            let mut defs = None;
+            let mut uses = Vec::new();
+
+            let declarator = {
+                let mut cursor = ast.walk();
+                ast.children(&mut cursor)
+                    .find(|c| matches!(c.kind(), "variable_declarator"))
+            };
+            if let Some(d) = declarator {
+                if let Some(name) = d.child_by_field_name("name") {
+                    let mut tmp = Vec::<String>::new();
+                    collect_idents(name, code, &mut tmp);
+                    defs = tmp.into_iter().next();
+                }
+                if let Some(val) = d.child_by_field_name("value") {
+                    collect_idents(val, code, &mut uses);
+                }
+            }
+            (defs, uses)
+        }
+
+        // `x = val;` as a statement: the binding sits on the wrapped
+        // `assignment_expression`, so read it from there.
+        "expression_statement"
+            if ast.named_child(0).is_some_and(|c| c.kind() == "assignment_expression") =>
+        {
+            def_use(ast.named_child(0).unwrap(), lang, code)
+        }
+
         // everything else – no definition, but may read vars
         _ => {
             let mut uses = Vec::new();
@@ -232,6 +370,12 @@ fn push_node<'a>(
         None
     };
 
+    let call_args = if kind == StmtKind::Call {
+        call_argument_idents(ast, lang, code)
+    } else {
+        Vec::new()
+    };
+
     let idx = g.add_node(NodeInfo {
         kind,
         span,
@@ -239,6 +383,7 @@ fn push_node<'a>(
         defines,
         uses,
         callee,
+        call_args,
     });
 
     debug!(
@@ -262,6 +407,376 @@ fn connect_all(g: &mut Cfg, froms: &[NodeIndex], to: NodeIndex, kind: EdgeKind)
     }
 }
 
+/// `left && right` / `left || right`: `left` is always evaluated and acts as
+/// the branch condition; `right` only runs when short-circuiting doesn't
+/// kick in (`left == true` for `&&`, `left == false` for `||`). The frontier
+/// is `cond` itself (the short-circuiting path, which never reaches `right`)
+/// joined with `right`'s own exits — the same shape as an `if` with no
+/// `else` block.
+fn build_short_circuit<'a>(
+    ast: Node<'a>,
+    preds: &[NodeIndex],
+    g: &mut Cfg,
+    lang: &str,
+    code: &'a [u8],
+    summaries: &mut FuncSummaries,
+    is_and: bool,
+) -> Vec<NodeIndex> {
+    let left = ast.child_by_field_name("left").unwrap_or(ast);
+    let right = ast.child_by_field_name("right").unwrap_or(ast);
+
+    let cond = push_node(g, StmtKind::If, left, lang, code);
+    connect_all(g, preds, cond, EdgeKind::Seq);
+
+    let taken_edge = if is_and { EdgeKind::True } else { EdgeKind::False };
+    // Build `right` with no predecessor wired in yet — every `build_sub` arm
+    // self-connects whatever `preds` it's handed, so passing `&[cond]` here
+    // would draw an unlabeled `Seq` edge from `cond` to `right`'s first node
+    // *in addition to* the `True`/`False` edge below, leaving a redundant
+    // parallel edge between the same pair of nodes.
+    let right_exits = build_sub(right, &[], g, lang, code, summaries);
+    if let Some(&first) = right_exits.first() {
+        connect_all(g, &[cond], first, taken_edge);
+    }
+
+    std::iter::once(cond).chain(right_exits).collect()
+}
+
+/// Every node belonging to the function whose header is `entry_idx`.
+/// `entry_idx`'s own span is the function's *whole* AST span — it's built
+/// via `push_node(g, StmtKind::Seq, ast, ...)` where `ast` is the function
+/// item itself — so every node nested inside that byte range is this
+/// function's, with no need to thread the AST node itself back in.
+fn fn_node_set(g: &Cfg, entry_idx: NodeIndex) -> HashSet<NodeIndex> {
+    let span = g[entry_idx].span;
+    g.node_indices()
+        .filter(|&idx| span.0 <= g[idx].span.0 && g[idx].span.1 <= span.1)
+        .collect()
+}
+
+/// Worklist fixpoint dataflow over one function's slice of the CFG: each
+/// node's `in_env` is the union of its *predecessors'* `out_env` along real
+/// CFG edges, so loop back-edges and if/else/match join points fold in
+/// naturally by iterating until nothing changes — a finite `Cap` lattice
+/// with monotone transfer functions (Source adds bits, Sanitizer clears
+/// them, Sink is identity) guarantees a fixpoint.
+///
+/// `Call` nodes additionally consult `summaries` for their callee: a
+/// `Source`/`Sanitizer` summary on the callee applies the same way calling
+/// one of `labels`'s built-in source/sanitizer functions would, letting taint
+/// (or the lack of it) flow through user-defined wrapper functions — not
+/// just direct `classify()` hits. Since `summaries` may still be incomplete
+/// or stale (forward references, recursion, mutual calls), this is meant to
+/// be called repeatedly as part of `build_cfg`'s phase-2 fixpoint, each call
+/// only ever reading the `summaries` snapshot passed to it.
+fn summarize_function(
+    g: &Cfg,
+    entry_idx: NodeIndex,
+    exit_idx: NodeIndex,
+    summaries: &FuncSummaries,
+) -> Option<DataLabel> {
+    let fn_nodes = fn_node_set(g, entry_idx);
+    let body_exits: Vec<NodeIndex> = g.neighbors_directed(exit_idx, Incoming).collect();
+
+    let mut env_out = HashMap::<NodeIndex, HashMap<String, Cap>>::new();
+    let mut node_bits = HashMap::<NodeIndex, Cap>::new();
+    let mut fn_src_bits = Cap::empty();
+    let mut fn_sani_bits = Cap::empty();
+    let mut fn_sink_bits = Cap::empty();
+
+    for &idx in &fn_nodes {
+        match g[idx].label {
+            Some(DataLabel::Sanitizer(bits)) => fn_sani_bits |= bits,
+            Some(DataLabel::Sink(bits)) => fn_sink_bits |= bits,
+            Some(DataLabel::Source(bits)) => fn_src_bits |= bits,
+            None => {}
+        }
+    }
+
+    let mut worklist: VecDeque<NodeIndex> = fn_nodes.iter().copied().collect();
+    let mut queued: HashSet<NodeIndex> = fn_nodes.clone();
+
+    while let Some(idx) = worklist.pop_front() {
+        queued.remove(&idx);
+        let info = &g[idx];
+
+        // in_env = union of every in-function predecessor's out_env
+        let mut in_env = HashMap::<String, Cap>::new();
+        for edge in g.edges_directed(idx, Incoming) {
+            if !fn_nodes.contains(&edge.source()) {
+                continue;
+            }
+            if let Some(pred_out) = env_out.get(&edge.source()) {
+                for (var, bits) in pred_out {
+                    *in_env.entry(var.clone()).or_insert(Cap::empty()) |= *bits;
+                }
+            }
+        }
+
+        //  a) incoming taint from any vars we read
+        let mut in_bits = Cap::empty();
+        for u in &info.uses {
+            if let Some(b) = in_env.get(u) {
+                in_bits |= *b;
+            }
+        }
+
+        //  b) apply this node's own label, or — for a call with no direct
+        //  label of its own — its callee's summary (transfer function)
+        let mut out_bits = in_bits;
+        if let Some(lab) = &info.label {
+            match *lab {
+                DataLabel::Source(bits) => out_bits |= bits,
+                DataLabel::Sanitizer(bits) => out_bits &= !bits,
+                DataLabel::Sink(_) => { /* no-op */ }
+            }
+        } else if info.kind == StmtKind::Call {
+            if let Some(callee) = &info.callee {
+                if let Some((_, _, Some(label), _)) = summaries.get(callee) {
+                    match *label {
+                        DataLabel::Source(bits) => out_bits |= bits,
+                        DataLabel::Sanitizer(bits) => out_bits &= !bits,
+                        DataLabel::Sink(_) => { /* no-op */ }
+                    }
+                }
+            }
+        }
+
+        //  c) write it back to the var we define (if any); every other
+        //  var just passes `in_env` straight through.
+        let mut out_env = in_env;
+        if let Some(def) = &info.defines {
+            if out_bits.is_empty() {
+                out_env.remove(def);
+            } else {
+                out_env.insert(def.clone(), out_bits);
+            }
+        }
+
+        node_bits.insert(idx, out_bits);
+
+        // Only re-enqueue successors if this node's out_env actually
+        // changed — otherwise we've already reached a fixpoint here.
+        let changed = env_out.get(&idx) != Some(&out_env);
+        env_out.insert(idx, out_env);
+
+        if changed {
+            for edge in g.edges_directed(idx, Outgoing) {
+                let succ = edge.target();
+                if fn_nodes.contains(&succ) && queued.insert(succ) {
+                    worklist.push_back(succ);
+                }
+            }
+        }
+    }
+
+    // now fold in any *explicit* returns
+    for (&idx, &bits) in &node_bits {
+        if g[idx].kind == StmtKind::Return {
+            fn_src_bits |= bits;
+        }
+    }
+
+    // …and *implicit* returns via fall-through from each exit predecessor
+    for &pred in &body_exits {
+        if let Some(&bits) = node_bits.get(&pred) {
+            fn_src_bits |= bits;
+        }
+    }
+
+    if !fn_sink_bits.is_empty() {
+        Some(DataLabel::Sink(fn_sink_bits))
+    } else if !fn_sani_bits.is_empty() {
+        Some(DataLabel::Sanitizer(fn_sani_bits))
+    } else if !fn_src_bits.is_empty() {
+        Some(DataLabel::Source(fn_src_bits))
+    } else {
+        None
+    }
+}
+
+/// The function item's parameter names, in declaration order — the same
+/// "first identifier in the pattern" rule `def_use` uses for a `let`
+/// binding, since a parameter pattern can destructure (`(a, b): (i32, i32)`)
+/// the same way a `let` pattern can.
+fn function_param_names(ast: Node, code: &[u8]) -> Vec<String> {
+    let Some(params) = ast.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+    let mut cursor = params.walk();
+    params
+        .children(&mut cursor)
+        .filter_map(|p| {
+            // Rust wraps each parameter in a `parameter` node with its
+            // binding under a `pattern` field; Java's `formal_parameter`
+            // names itself via a `name` field instead. JS/TS don't wrap
+            // parameters in anything at all — the child *is* the binding
+            // (a plain identifier, or an `assignment_pattern`/`rest_pattern`
+            // for defaults/rest) — so it's used as-is when neither field is
+            // present. Punctuation tokens (`,`, `(`, `)`) fall through to
+            // `collect_idents` finding nothing, filtered out below.
+            let pat = p
+                .child_by_field_name("pattern")
+                .or_else(|| p.child_by_field_name("name"))
+                .unwrap_or(p);
+            let mut tmp = Vec::new();
+            collect_idents(pat, code, &mut tmp);
+            tmp.into_iter().next()
+        })
+        .collect()
+}
+
+/// Learn what calling this function with `param` tainted would do, by
+/// running the same worklist dataflow [`summarize_function`] uses but
+/// seeded with `param` carrying every capability (`Cap::all()`) instead of
+/// computing taint from `classify()` hits alone. This is what lets a plain
+/// passthrough helper (`fn relay(x: T) -> T { x }`, no source/sink/sanitizer
+/// of its own) still propagate taint at a call site: `summarize_function`
+/// would see no labeled node anywhere in it and summarise it as `None`, but
+/// `to_return` here correctly comes back non-empty because the parameter's
+/// seeded taint reaches the return. Also reports `sanitizes`: the bits the
+/// function strips off the parameter itself (see [`ParamSummary::sanitizes`])
+/// for helpers that clean a `&mut` argument in place rather than returning a
+/// cleaned copy.
+fn propagate_param(
+    g: &Cfg,
+    entry_idx: NodeIndex,
+    exit_idx: NodeIndex,
+    param: &str,
+    summaries: &FuncSummaries,
+) -> ParamSummary {
+    let fn_nodes = fn_node_set(g, entry_idx);
+    let body_exits: Vec<NodeIndex> = g.neighbors_directed(exit_idx, Incoming).collect();
+
+    let mut env_out = HashMap::<NodeIndex, HashMap<String, Cap>>::new();
+    let mut node_bits = HashMap::<NodeIndex, Cap>::new();
+    let mut to_sink = Cap::empty();
+
+    let mut worklist: VecDeque<NodeIndex> = fn_nodes.iter().copied().collect();
+    let mut queued: HashSet<NodeIndex> = fn_nodes.clone();
+
+    while let Some(idx) = worklist.pop_front() {
+        queued.remove(&idx);
+        let info = &g[idx];
+
+        let mut in_env = HashMap::<String, Cap>::new();
+        if idx == entry_idx {
+            in_env.insert(param.to_string(), Cap::all());
+        }
+        for edge in g.edges_directed(idx, Incoming) {
+            if !fn_nodes.contains(&edge.source()) {
+                continue;
+            }
+            if let Some(pred_out) = env_out.get(&edge.source()) {
+                for (var, bits) in pred_out {
+                    *in_env.entry(var.clone()).or_insert(Cap::empty()) |= *bits;
+                }
+            }
+        }
+
+        let mut in_bits = Cap::empty();
+        for u in &info.uses {
+            if let Some(b) = in_env.get(u) {
+                in_bits |= *b;
+            }
+        }
+
+        // A direct or interprocedural sink hit, consulted *before* applying
+        // this node's own transfer function — a sink consumes the taint
+        // reaching it, it doesn't transform it for whatever's downstream.
+        match &info.label {
+            Some(DataLabel::Sink(bits)) if !(in_bits & *bits).is_empty() => {
+                to_sink |= in_bits & *bits;
+            }
+            None if info.kind == StmtKind::Call => {
+                if let Some(callee) = &info.callee {
+                    if let Some((_, _, Some(DataLabel::Sink(bits)), _)) = summaries.get(callee) {
+                        if !(in_bits & *bits).is_empty() {
+                            to_sink |= in_bits & *bits;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        let mut out_bits = in_bits;
+        if let Some(lab) = &info.label {
+            match *lab {
+                DataLabel::Source(bits) => out_bits |= bits,
+                DataLabel::Sanitizer(bits) => out_bits &= !bits,
+                DataLabel::Sink(_) => { /* no-op */ }
+            }
+        } else if info.kind == StmtKind::Call {
+            if let Some(callee) = &info.callee {
+                if let Some((_, _, Some(label), _)) = summaries.get(callee) {
+                    match *label {
+                        DataLabel::Source(bits) => out_bits |= bits,
+                        DataLabel::Sanitizer(bits) => out_bits &= !bits,
+                        DataLabel::Sink(_) => { /* no-op */ }
+                    }
+                }
+            }
+        }
+
+        let mut out_env = in_env;
+        if let Some(def) = &info.defines {
+            if out_bits.is_empty() {
+                out_env.remove(def);
+            } else {
+                out_env.insert(def.clone(), out_bits);
+            }
+        }
+
+        node_bits.insert(idx, out_bits);
+
+        let changed = env_out.get(&idx) != Some(&out_env);
+        env_out.insert(idx, out_env);
+
+        if changed {
+            for edge in g.edges_directed(idx, Outgoing) {
+                let succ = edge.target();
+                if fn_nodes.contains(&succ) && queued.insert(succ) {
+                    worklist.push_back(succ);
+                }
+            }
+        }
+    }
+
+    let mut to_return = Cap::empty();
+    for (&idx, &bits) in &node_bits {
+        if g[idx].kind == StmtKind::Return {
+            to_return |= bits;
+        }
+    }
+    for &pred in &body_exits {
+        if let Some(&bits) = node_bits.get(&pred) {
+            to_return |= bits;
+        }
+    }
+
+    // What's left of `param`'s own seeded taint at each exit — unioned
+    // (not intersected) across exits, so a bit only counts as sanitized
+    // below if *no* path leaves it standing.
+    let mut param_remaining = Cap::empty();
+    for &pred in &body_exits {
+        // An env without `param` means it was reassigned to a clean value;
+        // only an exit with no env at all is treated as fully tainted.
+        let bits = match env_out.get(&pred) {
+            Some(env) => env.get(param).copied().unwrap_or(Cap::empty()),
+            None => Cap::all(),
+        };
+        param_remaining |= bits;
+    }
+    let sanitizes = if body_exits.is_empty() {
+        Cap::empty()
+    } else {
+        Cap::all() & !param_remaining
+    };
+
+    ParamSummary { to_sink, to_return, sanitizes }
+}
+
 // -------------------------------------------------------------------------
 //    The recursive *work‑horse* that converts an AST node into a CFG slice.
 //    Returns the set of *exit* nodes that need to be wired further.
@@ -274,6 +789,17 @@ fn build_sub<'a>(
     code: &'a [u8],
     summaries: &mut FuncSummaries,
 ) -> Vec<NodeIndex> {
+    // Short-circuit `&&`/`||`: not its own node kind (tree-sitter calls it
+    // `binary_expression` same as every other operator), so it has to be
+    // intercepted here by operator text before the kind-based dispatch below.
+    if ast.kind() == "binary_expression" {
+        if let Some(op) = binary_operator_text(ast, code) {
+            if op == "&&" || op == "||" {
+                return build_short_circuit(ast, preds, g, lang, code, summaries, op == "&&");
+            }
+        }
+    }
+
     match lookup(lang, ast.kind()) {
         // ─────────────────────────────────────────────────────────────────
         //  IF‑/ELSE: two branches that re‑merge afterwards
@@ -284,12 +810,13 @@ fn build_sub<'a>(
             let cond = push_node(g, StmtKind::If, ast, lang, code);
             connect_all(g, preds, cond, EdgeKind::Seq);
 
-            // Locate then & else blocks
+            // Locate then & else blocks. Rust/Java both call the braced body
+            // `block`; JS calls the same shape `statement_block` instead.
             let (then_block, else_block) = {
                 let mut cursor = ast.walk();
                 let blocks: Vec<_> = ast
                     .children(&mut cursor)
-                    .filter(|n| n.kind() == "block")
+                    .filter(|n| matches!(n.kind(), "block" | "statement_block"))
                     .collect();
                 (blocks.first().copied(), blocks.get(1).copied())
             };
@@ -325,6 +852,92 @@ fn build_sub<'a>(
             then_exits.into_iter().chain(else_exits).collect()
         }
 
+        // ─────────────────────────────────────────────────────────────────
+        //  MATCH/switch: one branch per arm, all re-merging afterwards —
+        //  the same shape as If/else, just with N arms instead of 2.
+        // ─────────────────────────────────────────────────────────────────
+        Kind::Match => {
+            let scrutinee_uses = {
+                let mut tmp = Vec::new();
+                if let Some(value) = ast.child_by_field_name("value") {
+                    collect_idents(value, code, &mut tmp);
+                }
+                tmp
+            };
+            let scrutinee = push_node(g, StmtKind::If, ast, lang, code);
+            connect_all(g, preds, scrutinee, EdgeKind::Seq);
+
+            let mut arm_exits = Vec::new();
+            if let Some(body) = ast.child_by_field_name("body") {
+                let mut cursor = body.walk();
+                for arm in body
+                    .children(&mut cursor)
+                    .filter(|c| c.kind() == "match_arm")
+                {
+                    // The arm's pattern may bind identifiers (e.g. `Some(x)`),
+                    // so `defines` is the first one — the scrutinee's taint,
+                    // read via `uses`, propagates into the bound variable the
+                    // same way a `let` binding does.
+                    let pattern = arm.child_by_field_name("pattern");
+                    let defines = pattern.and_then(|pat| {
+                        let mut tmp = Vec::new();
+                        collect_idents(pat, code, &mut tmp);
+                        tmp.into_iter().next()
+                    });
+
+                    let arm_entry = g.add_node(NodeInfo {
+                        kind: StmtKind::Seq,
+                        span: (arm.start_byte(), arm.end_byte()),
+                        label: None,
+                        defines,
+                        uses: scrutinee_uses.clone(),
+                        callee: None,
+                        call_args: Vec::new(),
+                    });
+                    connect_all(g, &[scrutinee], arm_entry, EdgeKind::Case);
+
+                    let exits = if let Some(value) = arm.child_by_field_name("value") {
+                        build_sub(value, &[arm_entry], g, lang, code, summaries)
+                    } else {
+                        vec![arm_entry]
+                    };
+                    arm_exits.extend(exits);
+                }
+            }
+
+            if arm_exits.is_empty() {
+                vec![scrutinee]
+            } else {
+                arm_exits
+            }
+        }
+
+        // ─────────────────────────────────────────────────────────────────
+        //  TRY (`expr?`): normal fall-through carrying the unwrapped (still
+        //  possibly tainted) value, plus an implicit early-return edge for
+        //  the `Err`/`None` bail-out path — the same escape hatch an
+        //  explicit `return` is, just spliced in alongside the happy path
+        //  instead of replacing it.
+        // ─────────────────────────────────────────────────────────────────
+        Kind::Try => {
+            let node = push_node(g, StmtKind::Seq, ast, lang, code);
+            connect_all(g, preds, node, EdgeKind::Seq);
+
+            let (_, uses) = def_use(ast, lang, code);
+            let early_return = g.add_node(NodeInfo {
+                kind: StmtKind::Return,
+                span: (ast.start_byte(), ast.end_byte()),
+                label: None,
+                defines: None,
+                uses,
+                callee: None,
+                call_args: Vec::new(),
+            });
+            connect_all(g, &[node], early_return, EdgeKind::False);
+
+            vec![node]
+        }
+
         Kind::InfiniteLoop => {
             // Synthetic header node
             let header = push_node(g, StmtKind::Loop, ast, lang, code);
@@ -413,94 +1026,10 @@ fn build_sub<'a>(
             let body = ast.child_by_field_name("body").expect("fn w/o body");
             let body_exits = build_sub(body, &[entry_idx], g, lang, code, summaries);
 
-            // ───── 3) light-weight dataflow + capture both explicit & implicit returns ─
-            let mut var_taint = HashMap::<String, Cap>::new();
-            let mut node_bits = HashMap::<NodeIndex, Cap>::new();
-            let mut fn_src_bits = Cap::empty();
-            let mut fn_sani_bits = Cap::empty();
-            let mut fn_sink_bits = Cap::empty();
-
-            // first, sweep *all* nodes in this function and record their out_bits
-            for idx in g.node_indices() {
-                let info = &g[idx];
-                if info.span.0 < ast.start_byte() || info.span.1 > ast.end_byte() {
-                    continue;
-                }
-
-                // record any explicit sanitizer caps
-                if let Some(DataLabel::Sanitizer(bits)) = info.label {
-                        fn_sani_bits |= bits;
-                    }
-                // record any explicit sink caps
-                if let Some(DataLabel::Sink(bits)) = info.label {
-                        fn_sink_bits |= bits;
-                    }
-                // record any explicit source caps
-                if let Some(DataLabel::Source(bits)) = info.label {
-                        fn_src_bits |= bits;
-                    }
-
-                //  a) incoming taint from any vars we read
-                let mut in_bits = Cap::empty();
-                for u in &info.uses {
-                    if let Some(b) = var_taint.get(u) {
-                        in_bits |= *b;
-                    }
-                }
-
-                //  b) apply this node’s own label
-                let mut out_bits = in_bits;
-                if let Some(lab) = &info.label {
-                    match *lab {
-                        DataLabel::Source(bits) => out_bits |= bits,
-                        DataLabel::Sanitizer(bits) => out_bits &= !bits,
-                        DataLabel::Sink(_) => { /* no-op */ }
-                    }
-                }
-
-                //  c) write it back to the var we define (if any)
-                if let Some(def) = &info.defines {
-                    if out_bits.is_empty() {
-                        var_taint.remove(def);
-                    } else {
-                        var_taint.insert(def.clone(), out_bits);
-                    }
-                }
-
-                //  d) stash it for later
-                node_bits.insert(idx, out_bits);
-            }
-
-            // now fold in any *explicit* returns
-            for (&idx, &bits) in &node_bits {
-                if g[idx].kind == StmtKind::Return {
-                    fn_src_bits |= bits;
-                }
-            }
-
-            // …and *implicit* returns via fall-through from each exit predecessor
-            for &pred in &body_exits {
-                if let Some(&bits) = node_bits.get(&pred) {
-                    fn_src_bits |= bits;
-                }
-            }
-
-            let fn_label = fn_src_bits
-                .is_empty()
-                .then(|| None)
-                .unwrap_or(Some(DataLabel::Source(fn_src_bits)));
-
-            let fn_summary_label = if !fn_sink_bits.is_empty() {
-                Some(DataLabel::Sink(fn_sink_bits))
-            } else if !fn_sani_bits.is_empty() {
-            Some(DataLabel::Sanitizer(fn_sani_bits))
-        } else if !fn_src_bits.is_empty() {
-            Some(DataLabel::Source(fn_src_bits))
-        } else {
-            None
-        };
-
-            /* ───── 4) synthesise an explicit exit-node and wire it up ──────────── */
+            // 3) synthesise an explicit exit-node and wire it up, *before*
+            // summarising — `summarize_function` reads `body_exits` back out
+            // as `exit_idx`'s predecessors, and interprocedural re-summarising
+            // (phase 2, in `build_cfg`) needs `exit_idx` to already exist.
             let exit_idx = g.add_node(NodeInfo {
                 kind: StmtKind::Return,
                 span: (ast.start_byte(), ast.end_byte()),
@@ -508,13 +1037,37 @@ fn build_sub<'a>(
                 defines: None,
                 uses: Vec::new(),
                 callee: None,
+                call_args: Vec::new(),
             });
             for &b in &body_exits {
                 connect_all(g, &[b], exit_idx, EdgeKind::Seq);
             }
 
-            /* ───── 5) store the summary – *don’t* overwrite it later! ──────────── */
-            summaries.insert(fn_name.clone(), (entry_idx, exit_idx, fn_summary_label));
+            // 4) worklist fixpoint dataflow over this function's slice of the
+            // CFG (see `summarize_function`) — only a *local* summary at this
+            // point, since sibling functions defined later in the file (or
+            // mutually recursive with this one) haven't been summarised yet.
+            // `build_cfg`'s phase-2 loop re-runs this to a fixpoint once every
+            // function in the file has an initial summary.
+            let fn_summary_label = summarize_function(g, entry_idx, exit_idx, summaries);
+
+            // 4b) per-parameter summary — which params reach a sink / the
+            // return value (see `ParamSummary`), re-run to the same fixpoint
+            // as the whole-function label in `build_cfg`'s phase 2.
+            let param_summaries: Vec<(String, ParamSummary)> = function_param_names(ast, code)
+                .into_iter()
+                .map(|p| {
+                    let summary = propagate_param(g, entry_idx, exit_idx, &p, summaries);
+                    (p, summary)
+                })
+                .collect();
+
+            // 5) store the initial summary – `build_cfg`'s phase 2 replaces
+            //    it while callee summaries are still settling
+            summaries.insert(
+                fn_name.clone(),
+                (entry_idx, exit_idx, fn_summary_label, param_summaries),
+            );
 
             vec![exit_idx]
         }
@@ -523,12 +1076,10 @@ fn build_sub<'a>(
         Kind::CallWrapper => {
             let mut cursor = ast.walk();
 
-            if let Some(inner) = ast.children(&mut cursor).find(|c| {
-                matches!(
-                    lookup(lang, c.kind()),
-                    Kind::InfiniteLoop | Kind::While | Kind::For | Kind::If
-                )
-            }) {
+            if let Some(inner) = ast
+                .children(&mut cursor)
+                .find(|c| is_control_construct(lang, *c, code))
+            {
                 return build_sub(inner, preds, g, lang, code, summaries);
             }
 
@@ -590,6 +1141,7 @@ pub(crate) fn build_cfg<'a>(
         defines: None,
         uses: Vec::new(),
         callee: None,
+        call_args: Vec::new(),
     });
     let exit = g.add_node(NodeInfo {
         kind: StmtKind::Exit,
@@ -598,6 +1150,7 @@ pub(crate) fn build_cfg<'a>(
         defines: None,
         uses: Vec::new(),
         callee: None,
+        call_args: Vec::new(),
     });
 
     // Build the body below the synthetic ENTRY.
@@ -615,6 +1168,51 @@ pub(crate) fn build_cfg<'a>(
         connect_all(&mut g, &[e], exit, EdgeKind::Seq);
     }
 
+    // Phase 2: interprocedural fixpoint. Every function in the file now has
+    // an initial summary, but each was computed only against whichever
+    // *other* summaries already existed at the time — a function calling
+    // one defined later in the file, or part of a recursive/mutual-call
+    // cycle, saw a stale (missing) callee summary. Re-summarise everyone
+    // against the latest snapshot until nothing changes, capped so a
+    // recursive cycle can't spin forever.
+    const MAX_SUMMARY_ITERS: usize = 8;
+    for _ in 0..MAX_SUMMARY_ITERS {
+        let fn_entries: Vec<(String, NodeIndex, NodeIndex, Vec<String>)> = summaries
+            .iter()
+            .map(|(name, (entry_idx, exit_idx, _, params))| {
+                (
+                    name.clone(),
+                    *entry_idx,
+                    *exit_idx,
+                    params.iter().map(|(p, _)| p.clone()).collect(),
+                )
+            })
+            .collect();
+
+        let mut changed = false;
+        for (name, entry_idx, exit_idx, param_names) in fn_entries {
+            let new_label = summarize_function(&g, entry_idx, exit_idx, &summaries);
+            let new_params: Vec<(String, ParamSummary)> = param_names
+                .into_iter()
+                .map(|p| {
+                    let summary = propagate_param(&g, entry_idx, exit_idx, &p, &summaries);
+                    (p, summary)
+                })
+                .collect();
+
+            let old = summaries.get(&name);
+            let old_label = old.and_then(|(_, _, l, _)| *l);
+            let old_params = old.map(|(_, _, _, p)| p.clone());
+            if new_label != old_label || old_params.as_ref() != Some(&new_params) {
+                changed = true;
+            }
+            summaries.insert(name, (entry_idx, exit_idx, new_label, new_params));
+        }
+        if !changed {
+            break;
+        }
+    }
+
     debug!(target: "cfg", "CFG DONE — nodes: {}, edges: {}", g.node_count(), g.edge_count());
 
     if cfg!(debug_assertions) {
@@ -661,12 +1259,92 @@ pub(crate) fn build_cfg<'a>(
     (g, entry, summaries)
 }
 
-pub(crate) fn dump_cfg(g: &Cfg) {
+/// A single fragment-conjunction filter over a node's `{:?}` dump: it
+/// matches a node only if *every* `&`-separated, trimmed fragment is a
+/// substring of that node's debug string. Empty text (or text that's
+/// all-whitespace/`&`) produces no fragments and matches every node.
+#[derive(Debug, Clone)]
+pub struct NodeFilter {
+    fragments: Vec<String>,
+}
+
+impl NodeFilter {
+    pub fn new(text: &str) -> Self {
+        let fragments = text
+            .split('&')
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+            .map(str::to_owned)
+            .collect();
+        NodeFilter { fragments }
+    }
+
+    pub fn matches(&self, debug_str: &str) -> bool {
+        self.fragments.iter().all(|f| debug_str.contains(f.as_str()))
+    }
+}
+
+/// An edge filter of the form `"<source filter> -> <target filter>"`: the
+/// part before the first `->` is a [`NodeFilter`] tested against the edge's
+/// source node, the part after against its target node, independently of
+/// each other. Text with no `->` is treated as a source-only filter.
+#[derive(Debug, Clone)]
+pub struct EdgeFilter {
+    pub source: NodeFilter,
+    pub target: NodeFilter,
+}
+
+impl EdgeFilter {
+    pub fn new(text: &str) -> Self {
+        match text.split_once("->") {
+            Some((src, tgt)) => EdgeFilter {
+                source: NodeFilter::new(src),
+                target: NodeFilter::new(tgt),
+            },
+            None => EdgeFilter {
+                source: NodeFilter::new(text),
+                target: NodeFilter::new(""),
+            },
+        }
+    }
+
+    pub fn matches(&self, src_debug: &str, tgt_debug: &str) -> bool {
+        self.source.matches(src_debug) && self.target.matches(tgt_debug)
+    }
+}
+
+/// Dumps `g` to the `taint` log target, optionally narrowed by an
+/// `EdgeFilter` query (e.g. `"arg & fn_foo -> sink"`): only edges whose
+/// source/target debug strings match are printed, and only the nodes those
+/// edges touch. `filter: None` reproduces the old unfiltered full dump.
+pub(crate) fn dump_graph(g: &Cfg, filter: Option<&str>) {
+    let edge_filter = filter.map(EdgeFilter::new);
+
+    let matching_edges: Vec<_> = g
+        .edge_references()
+        .filter(|e| {
+            edge_filter.as_ref().map_or(true, |ef| {
+                ef.matches(
+                    &format!("{:?}", g[e.source()]),
+                    &format!("{:?}", g[e.target()]),
+                )
+            })
+        })
+        .collect();
+
+    let shown_nodes: HashSet<NodeIndex> = matching_edges
+        .iter()
+        .flat_map(|e| [e.source(), e.target()])
+        .collect();
+
     debug!(target: "taint", "CFG DUMP: nodes = {}, edges = {}", g.node_count(), g.edge_count());
     for idx in g.node_indices() {
+        if edge_filter.is_some() && !shown_nodes.contains(&idx) {
+            continue;
+        }
         debug!(target: "taint", "  node {:>3}: {:?}", idx.index(), g[idx]);
     }
-    for e in g.edge_references() {
+    for e in &matching_edges {
         debug!(
             target: "taint",
             "  edge {:>3} → {:<3} ({:?})",
@@ -676,3 +1354,153 @@ pub(crate) fn dump_cfg(g: &Cfg) {
         );
     }
 }
+
+#[test]
+fn match_arms_each_get_one_case_edge_converging_at_a_shared_successor() {
+    use tree_sitter::Language;
+
+    let src = br#"
+        fn main() {
+            let x = 1;
+            match x {
+                1 => {}
+                2 => {}
+                _ => {}
+            }
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (g, _entry, _summaries) = build_cfg(&tree, src, "rust");
+
+    let scrutinee = g
+        .node_indices()
+        .find(|&i| g[i].kind == StmtKind::If)
+        .expect("match scrutinee should be a single If-kind node");
+
+    let case_edges: Vec<_> = g
+        .edges_directed(scrutinee, Outgoing)
+        .filter(|e| *e.weight() == EdgeKind::Case)
+        .collect();
+    assert_eq!(case_edges.len(), 3, "one Case edge per arm");
+
+    let arm_entries: HashSet<NodeIndex> = case_edges.iter().map(|e| e.target()).collect();
+    assert_eq!(arm_entries.len(), 3, "each arm gets its own entry node");
+
+    let mut successors: HashSet<NodeIndex> = HashSet::new();
+    for &arm_entry in &arm_entries {
+        assert_eq!(g[arm_entry].kind, StmtKind::Seq);
+        assert_eq!(g[arm_entry].uses, vec!["x".to_string()]);
+        assert_eq!(
+            g.edges_directed(arm_entry, Incoming).count(),
+            1,
+            "an arm entry has no predecessor besides its Case edge"
+        );
+
+        let outgoing: Vec<_> = g.edges_directed(arm_entry, Outgoing).collect();
+        assert_eq!(outgoing.len(), 1, "each empty arm body falls through once");
+        assert_eq!(*outgoing[0].weight(), EdgeKind::Seq);
+        successors.insert(outgoing[0].target());
+    }
+    assert_eq!(successors.len(), 1, "all arms re-merge at the same successor");
+}
+
+#[test]
+fn short_circuit_and_cond_has_one_true_edge_and_one_skip_edge() {
+    use tree_sitter::Language;
+
+    let src = br#"
+        fn check(a: bool) -> bool {
+            let r = a && is_ready();
+            r
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (g, _entry, _summaries) = build_cfg(&tree, src, "rust");
+
+    let cond = g
+        .node_indices()
+        .find(|&i| g[i].kind == StmtKind::If)
+        .expect("the `&&`'s left operand should be a single If-kind node");
+
+    let outgoing: Vec<_> = g.edges_directed(cond, Outgoing).collect();
+    assert_eq!(
+        outgoing.len(),
+        2,
+        "cond should have exactly one True edge (into the right operand) and \
+         one Seq edge (the short-circuit path straight to the merge point), \
+         not a duplicate Seq edge alongside the True edge"
+    );
+
+    let true_targets: Vec<_> = outgoing
+        .iter()
+        .filter(|e| *e.weight() == EdgeKind::True)
+        .map(|e| e.target())
+        .collect();
+    let seq_targets: Vec<_> = outgoing
+        .iter()
+        .filter(|e| *e.weight() == EdgeKind::Seq)
+        .map(|e| e.target())
+        .collect();
+    assert_eq!(true_targets.len(), 1);
+    assert_eq!(seq_targets.len(), 1);
+    assert_ne!(true_targets[0], seq_targets[0]);
+
+    // The right operand re-merges with cond's short-circuit path at the same node.
+    let right_node = true_targets[0];
+    let merge_node = seq_targets[0];
+    let right_outgoing: Vec<_> = g.edges_directed(right_node, Outgoing).collect();
+    assert_eq!(right_outgoing.len(), 1);
+    assert_eq!(*right_outgoing[0].weight(), EdgeKind::Seq);
+    assert_eq!(right_outgoing[0].target(), merge_node);
+}
+
+#[test]
+fn short_circuit_or_cond_has_one_false_edge_and_one_skip_edge() {
+    use tree_sitter::Language;
+
+    let src = br#"
+        fn check(a: bool) -> bool {
+            let r = a || is_ready();
+            r
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (g, _entry, _summaries) = build_cfg(&tree, src, "rust");
+
+    let cond = g
+        .node_indices()
+        .find(|&i| g[i].kind == StmtKind::If)
+        .expect("the `||`'s left operand should be a single If-kind node");
+
+    let outgoing: Vec<_> = g.edges_directed(cond, Outgoing).collect();
+    assert_eq!(outgoing.len(), 2);
+
+    let false_targets: Vec<_> = outgoing
+        .iter()
+        .filter(|e| *e.weight() == EdgeKind::False)
+        .map(|e| e.target())
+        .collect();
+    let seq_targets: Vec<_> = outgoing
+        .iter()
+        .filter(|e| *e.weight() == EdgeKind::Seq)
+        .map(|e| e.target())
+        .collect();
+    assert_eq!(false_targets.len(), 1);
+    assert_eq!(seq_targets.len(), 1);
+    assert_ne!(false_targets[0], seq_targets[0]);
+}
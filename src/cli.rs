@@ -32,6 +32,29 @@ pub enum Commands {
         /// Show only high severity issues
         #[arg(long)]
         high_only: bool,
+
+        /// Print each console finding's suggested fix (description and
+        /// rewrite template) instead of a short hint; files are not modified
+        #[arg(long)]
+        fix: bool,
+
+        /// Gate on a named baseline instead of reporting every finding:
+        /// only newly introduced issues (and any that disappeared) are shown.
+        /// Create one first with `nyx index baseline <name>`.
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Only run AST pattern queries, skipping the taint dataflow pass.
+        #[arg(long, conflicts_with = "cfg_only")]
+        ast_only: bool,
+
+        /// Only run the taint dataflow pass, skipping AST pattern queries.
+        #[arg(long, conflicts_with = "ast_only")]
+        cfg_only: bool,
+
+        /// Run both passes regardless of `nyx.local`'s configured mode.
+        #[arg(long)]
+        all_targets: bool,
     },
 
     /// Manage project indexes
@@ -56,6 +79,71 @@ pub enum Commands {
         #[arg(long)]
         all: bool,
     },
+
+    /// Run rule-regression fixtures, checking `// EXPECT:` / `// NO-FINDING`
+    /// annotations against what the scanner actually reports
+    Test {
+        /// Directory of annotated fixtures to check
+        #[arg(default_value = ".")]
+        dir: String,
+    },
+
+    /// Watch a project and re-scan only the files that change, instead of
+    /// a one-shot `nyx scan`
+    Watch {
+        /// Path to watch (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Speak the Language Server Protocol over stdio (publishing
+        /// diagnostics per open document) instead of printing deltas to
+        /// the console — for editor integration rather than interactive use.
+        #[arg(long)]
+        lsp: bool,
+    },
+
+    /// Search a project's already-built index without rescanning
+    Query {
+        /// Project path whose index should be searched (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Query DSL expression, e.g. `severity:high rule:sql_injection`,
+        /// `(lang:rs OR lang:go) NOT path:vendor/*` (`lang:` takes a file
+        /// extension). Takes precedence over
+        /// the `--severity`/`--rule`/`--path-prefix` flags when given.
+        #[arg(long = "expr")]
+        expr: Option<String>,
+
+        /// Only show issues at least this severe (high, medium, low)
+        #[arg(long, default_value = "low")]
+        severity: String,
+
+        /// Only show issues whose rule id contains this substring
+        #[arg(long)]
+        rule: Option<String>,
+
+        /// Fuzzy-match a rule id against the index's distinct rule ids (via
+        /// an in-memory FST) instead of an exact/substring match — e.g.
+        /// `--fuzzy taint_cmd` matches `taint_cmd_injection`. Tries a prefix
+        /// match first, falling back to Levenshtein distance 2; both compare
+        /// against whole rule ids from their start, so a term taken from the
+        /// middle of an id (`cmd-inj`) matches nothing.
+        #[arg(long, conflicts_with = "rule")]
+        fuzzy: Option<String>,
+
+        /// Only show issues whose file path starts with this prefix
+        #[arg(long)]
+        path_prefix: Option<String>,
+
+        /// Cap the number of results returned
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "")]
+        format: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -77,4 +165,15 @@ pub enum IndexAction {
         #[arg(default_value = ".")]
         path: String,
     },
+
+    /// Snapshot the current issue set as a named baseline, so a later
+    /// `nyx scan --baseline <name>` only fails on new regressions
+    Baseline {
+        /// Project path whose index should be snapshotted (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Name of the baseline to create or overwrite
+        name: String,
+    },
 }
@@ -21,6 +21,8 @@ pub fn handle(
     match action {
         IndexAction::Build { path, force } => {
             let build_path = std::path::Path::new(&path).canonicalize()?;
+            let layered_config = config.layered_for_path(&build_path)?;
+            let config = &layered_config;
             let (project_name, db_path) = get_project_info(&build_path, database_dir)?;
 
             if force || !db_path.exists() {
@@ -30,15 +32,17 @@ pub fn handle(
                     style("Index built:").green(),
                     style(db_path.display()).white().bold()
                 );
-                exit(0);
             } else {
+                let stats = update_index(&project_name, &build_path, &db_path, config)?;
                 println!(
-                    "{} {}",
-                    style("↩ Index already exists").yellow(),
-                    style("(use --force to rebuild)").dim()
+                    "✔ {} {} ({} changed, {} removed)",
+                    style("Index updated:").green(),
+                    style(db_path.display()).white().bold(),
+                    stats.changed,
+                    stats.removed
                 );
-                exit(0);
             }
+            exit(0);
         }
         IndexAction::Status { path } => {
             let status_path = std::path::Path::new(&path).canonicalize()?;
@@ -75,6 +79,31 @@ pub fn handle(
 
             exit(0);
         }
+        IndexAction::Baseline { path, name } => {
+            let base_path = std::path::Path::new(&path).canonicalize()?;
+            let (project_name, db_path) = get_project_info(&base_path, database_dir)?;
+
+            if !db_path.exists() {
+                println!(
+                    "{} no index found for '{}' (run `nyx index build` first)",
+                    style("note").yellow().bold(),
+                    project_name
+                );
+                exit(1);
+            }
+
+            let pool = Indexer::init(&db_path)?;
+            let mut idx = Indexer::from_pool(&project_name, &pool)?;
+            idx.create_baseline(&name)?;
+
+            println!(
+                "✔ {} '{}' for '{}'",
+                style("Baseline saved:").green(),
+                style(&name).white().bold(),
+                project_name
+            );
+            exit(0);
+        }
     }
 }
 
@@ -106,22 +135,7 @@ pub fn build_index(
             let issues = crate::commands::scan::run_rules_on_file(&path, config)?;
             let mut idx = Indexer::from_pool(project_name, &pool)?;
             let file_id = idx.upsert_file(&path)?;
-
-            let rows: Vec<IssueRow> = issues
-                .iter()
-                .map(|d| IssueRow {
-                    rule_id: d.id.as_ref(),
-                    severity: match d.severity {
-                        Severity::High => "HIGH",
-                        Severity::Medium => "MEDIUM",
-                        Severity::Low => "LOW",
-                    },
-                    line: d.line as i64,
-                    col: d.col as i64,
-                })
-                .collect();
-
-            idx.replace_issues(file_id, rows)?;
+            idx.replace_issues(file_id, issue_rows(&issues))?;
             Ok(())
         },
     )?;
@@ -134,6 +148,87 @@ pub fn build_index(
     Ok(())
 }
 
+/// Outcome of an [`update_index`] run.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IndexUpdateStats {
+    /// Files that were re-parsed and re-scanned because their content or mtime changed.
+    pub changed: usize,
+    /// Previously-indexed files no longer found on disk.
+    pub removed: usize,
+}
+
+/// Incrementally refresh an existing index instead of rebuilding it from
+/// scratch: walk the project, skip any file whose content hash and mtime
+/// already match what's stored (via [`Indexer::should_scan`]), re-scan only
+/// the rest, and drop rows for files that disappeared. This is what `nyx
+/// index build` runs without `--force`, turning repeat indexing of a large
+/// tree into O(changed files) instead of O(all files).
+pub fn update_index(
+    project_name: &str,
+    project_path: &std::path::Path,
+    db_path: &std::path::Path,
+    config: &Config,
+) -> NyxResult<IndexUpdateStats> {
+    tracing::debug!("Incrementally updating index for: {}", project_name);
+
+    let pool = Indexer::init(db_path)?;
+
+    let (rx, handle) = spawn_file_walker(&project_path, &config);
+    if let Err(err) = handle.join() {
+        tracing::error!("walker thread panicked: {:#?}", err);
+    }
+    let paths: Vec<PathBuf> = rx.into_iter().flatten().collect();
+
+    let changed = std::sync::atomic::AtomicUsize::new(0);
+
+    paths.par_iter().try_for_each(
+        |path| -> NyxResult<()> {
+            let mut idx = Indexer::from_pool(project_name, &pool)?;
+            if !idx.should_scan(path)? {
+                return Ok(());
+            }
+
+            let issues = crate::commands::scan::run_rules_on_file(path, config)?;
+            let file_id = idx.upsert_file(path)?;
+            idx.replace_issues(file_id, issue_rows(&issues))?;
+            changed.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(())
+        },
+    )?;
+
+    let removed = {
+        let mut idx = Indexer::from_pool(project_name, &pool)?;
+        idx.remove_missing_files(project_name, paths.iter().map(PathBuf::as_path))?
+    };
+
+    Ok(IndexUpdateStats {
+        changed: changed.load(std::sync::atomic::Ordering::Relaxed),
+        removed,
+    })
+}
+
+/// Map scan findings onto the row shape `Indexer::replace_issues` expects.
+fn issue_rows(issues: &[crate::commands::scan::Diag]) -> Vec<IssueRow<'_>> {
+    let ordinals = crate::commands::scan::fingerprint_ordinals(issues);
+    issues
+        .iter()
+        .zip(&ordinals)
+        .map(|(d, &ordinal)| IssueRow {
+            rule_id: d.id.as_ref(),
+            severity: match d.severity {
+                Severity::High => "HIGH",
+                Severity::Medium => "MEDIUM",
+                Severity::Low => "LOW",
+            },
+            line: d.line as i64,
+            col: d.col as i64,
+            caps: d.caps.bits(),
+            fingerprint: d.fingerprint(ordinal),
+            spans_json: serde_json::to_string(&d.spans).unwrap_or_default(),
+        })
+        .collect()
+}
+
 #[test]
 fn build_index_creates_db_and_registers_files() {
     let mut cfg = Config::default();
@@ -160,3 +255,34 @@ fn build_index_creates_db_and_registers_files() {
     assert_eq!(files.len(), 1, "exactly one file indexed");
     assert_eq!(files[0], f_txt);
 }
+
+#[test]
+fn update_index_skips_unchanged_files_and_prunes_deleted() {
+    let mut cfg = Config::default();
+    cfg.performance.worker_threads = Some(1);
+    cfg.performance.channel_multiplier = 1;
+    cfg.performance.batch_size = 2;
+
+    let td = tempfile::tempdir().unwrap();
+    let project_dir = td.path().join("proj");
+    fs::create_dir(&project_dir).unwrap();
+    let stable = project_dir.join("stable.txt");
+    let doomed = project_dir.join("doomed.txt");
+    fs::write(&stable, "hello").unwrap();
+    fs::write(&doomed, "world").unwrap();
+
+    let db_path = td.path().join("proj.sqlite");
+    build_index("proj", &project_dir, &db_path, &cfg).expect("initial build should succeed");
+
+    fs::remove_file(&doomed).unwrap();
+
+    let stats =
+        update_index("proj", &project_dir, &db_path, &cfg).expect("incremental update should succeed");
+    assert_eq!(stats.changed, 0, "stable.txt's content/mtime didn't change");
+    assert_eq!(stats.removed, 1, "doomed.txt should be pruned");
+
+    let pool = Indexer::init(&db_path).unwrap();
+    let idx = Indexer::from_pool("proj", &pool).unwrap();
+    let files = idx.get_files("proj").unwrap();
+    assert_eq!(files, vec![stable]);
+}
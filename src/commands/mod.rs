@@ -1,7 +1,10 @@
 pub mod clean;
 pub mod index;
 pub mod list;
+pub mod query;
+pub mod report;
 pub mod scan;
+pub mod test;
 
 use crate::cli::Commands;
 use crate::errors::NyxResult;
@@ -21,6 +24,8 @@ pub fn handle_command(
             rebuild_index,
             format,
             high_only,
+            fix,
+            baseline,
             ast_only,
             cfg_only,
             all_targets,
@@ -41,10 +46,41 @@ pub fn handle_command(
                 config.scanner.mode = AnalysisMode::Full
             };
 
-            scan::handle(&path, no_index, rebuild_index, format, database_dir, config)
+            scan::handle(
+                &path,
+                no_index,
+                rebuild_index,
+                format,
+                fix,
+                baseline,
+                database_dir,
+                config,
+            )
         }
+        Commands::Watch { path, lsp } => crate::daemon::handle(&path, lsp, config),
         Commands::Index { action } => index::handle(action, database_dir, config),
         Commands::List { verbose } => list::handle(verbose, database_dir),
         Commands::Clean { project, all } => clean::handle(project, all, database_dir),
+        Commands::Test { dir } => test::handle(&dir, config),
+        Commands::Query {
+            path,
+            expr,
+            severity,
+            rule,
+            fuzzy,
+            path_prefix,
+            limit,
+            format,
+        } => query::handle(
+            &path,
+            expr,
+            severity.parse().unwrap_or(Severity::Low),
+            rule,
+            fuzzy,
+            path_prefix,
+            limit,
+            format,
+            database_dir,
+        ),
     }
 }
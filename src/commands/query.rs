@@ -0,0 +1,166 @@
+//! `nyx query` — read-only triage over an already-built index.
+//!
+//! Unlike `nyx scan`, this never touches the filesystem or reparses
+//! anything: it opens the project's SQLite pool via `Indexer::init` and
+//! issues filtered `SELECT`s (one, or with `--fuzzy` one per matched rule
+//! id after a distinct-rule-id lookup), then reuses the scan output
+//! formatters so the results look the same in console/json/sarif.
+//!
+//! `--expr` runs a small query DSL ([`crate::utils::query_lang`]) instead of
+//! the flag-based filter below — `field:value` pairs, quoted strings,
+//! `AND`/`OR`/`NOT`, and parentheses, compiled to a parameterized SQL
+//! `WHERE` clause (or interpreted directly against the in-memory store).
+
+use crate::database::index::{Indexer, IssueFilter};
+use crate::errors::{NyxError, NyxResult};
+use crate::patterns::Severity;
+use crate::utils::project::get_project_info;
+use crate::utils::query_lang;
+use console::style;
+use fst::automaton::{Levenshtein, Str};
+use fst::{IntoStreamer, Set, Streamer};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Rule ids in `ids` (must be sorted, as returned by
+/// [`Indexer::distinct_rule_ids`]) that fuzzy-match `term`: a prefix match
+/// first, falling back to a Levenshtein-distance-2 match if nothing starts
+/// with `term`. Built as an in-memory FST rather than scanned linearly, so
+/// this stays cheap even over a large rule set.
+fn fuzzy_rule_ids(ids: &[String], term: &str) -> NyxResult<Vec<String>> {
+    let set = Set::from_iter(ids.iter()).map_err(|e| NyxError::Msg(e.to_string()))?;
+
+    let mut matches: Vec<String> = {
+        let mut stream = set.search(Str::new(term).starts_with()).into_stream();
+        let mut out = Vec::new();
+        while let Some(key) = stream.next() {
+            out.push(String::from_utf8_lossy(key).into_owned());
+        }
+        out
+    };
+
+    if matches.is_empty() {
+        if let Ok(lev) = Levenshtein::new(term, 2) {
+            let mut stream = set.search(lev).into_stream();
+            while let Some(key) = stream.next() {
+                matches.push(String::from_utf8_lossy(key).into_owned());
+            }
+        }
+    }
+
+    Ok(matches)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    path: &str,
+    expr: Option<String>,
+    min_severity: Severity,
+    rule: Option<String>,
+    fuzzy: Option<String>,
+    path_prefix: Option<String>,
+    limit: Option<usize>,
+    format: String,
+    database_dir: &Path,
+) -> NyxResult<()> {
+    let scan_path = Path::new(path).canonicalize()?;
+    let (project_name, db_path) = get_project_info(&scan_path, database_dir)?;
+
+    if !db_path.exists() {
+        println!(
+            "{} no index found for '{}' (run `nyx index build` first)",
+            style("note").yellow().bold(),
+            project_name
+        );
+        return Ok(());
+    }
+
+    let pool = Indexer::init(&db_path)?;
+    let idx = Indexer::from_pool(&project_name, &pool)?;
+
+    let diags = if let Some(expr) = expr {
+        let parsed = query_lang::parse(&expr)?;
+        idx.query_expr(&project_name, &parsed)?
+    } else if let Some(term) = fuzzy {
+        let ids = idx.distinct_rule_ids(&project_name)?;
+        let matched = fuzzy_rule_ids(&ids, &term)?;
+
+        println!(
+            "{} fuzzy '{}' matched rule(s): {}\n",
+            style("note").green().bold(),
+            term,
+            if matched.is_empty() {
+                style("<none>").dim().to_string()
+            } else {
+                matched.join(", ")
+            }
+        );
+
+        let mut diags = Vec::new();
+        for rule_id in &matched {
+            let filter = IssueFilter {
+                min_severity,
+                rule_like: Some(rule_id.clone()),
+                path_prefix: path_prefix.clone(),
+                limit: None,
+            };
+            diags.extend(idx.query_issues(&filter)?);
+        }
+        diags.sort_by(|a, b| (&a.path, a.line, a.col).cmp(&(&b.path, b.line, b.col)));
+        if let Some(limit) = limit {
+            diags.truncate(limit);
+        }
+        diags
+    } else {
+        let filter = IssueFilter {
+            min_severity,
+            rule_like: rule.map(|r| format!("%{r}%")),
+            path_prefix,
+            limit,
+        };
+        idx.query_issues(&filter)?
+    };
+
+    if format == "sarif" {
+        println!("{}", serde_json::to_string_pretty(&crate::commands::report::to_sarif(&diags))?);
+        return Ok(());
+    }
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&crate::commands::report::to_json(&diags))?);
+        return Ok(());
+    }
+
+    if format == "ndjson" {
+        println!("{}", crate::commands::report::to_json_lines(&diags));
+        return Ok(());
+    }
+
+    let mut grouped: BTreeMap<&str, Vec<&crate::commands::scan::Diag>> = BTreeMap::new();
+    for d in &diags {
+        grouped.entry(&d.path).or_default().push(d);
+    }
+
+    for (path, issues) in &grouped {
+        println!("{}", style(path).blue().underlined());
+        for d in issues {
+            println!(
+                "  {:>4}:{:<4}  [{}]  {}",
+                d.line,
+                d.col,
+                d.severity,
+                style(&d.id).bold()
+            );
+        }
+        println!();
+    }
+
+    println!(
+        "{} '{}' matched {} issue(s) in the index.",
+        style("note").green().bold(),
+        style(&project_name).white().bold(),
+        style(diags.len()).bold()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,247 @@
+use crate::commands::scan::Diag;
+use crate::patterns::{self, Severity};
+use serde_json::{Value, json};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Build a SARIF `region` object, adding `endLine`/`endColumn` only when the
+/// diag actually has an end position (see `Diag::end_line`).
+fn span_region(line: usize, col: usize, end_line: Option<usize>, end_col: Option<usize>) -> Value {
+    let mut region = json!({ "startLine": line, "startColumn": col });
+    if let (Some(end_line), Some(end_col)) = (end_line, end_col) {
+        let region = region.as_object_mut().unwrap();
+        region.insert("endLine".to_string(), json!(end_line));
+        region.insert("endColumn".to_string(), json!(end_col));
+    }
+    region
+}
+
+/// Map our three-tier severity onto SARIF's `level` enum.
+fn sarif_level(sev: Severity) -> &'static str {
+    match sev {
+        Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "note",
+    }
+}
+
+/// Render `diags` as a SARIF 2.1.0 log so results can be uploaded to
+/// code-scanning dashboards and gated in CI.
+///
+/// Each distinct rule id appears once under `tool.driver.rules`, with its
+/// `description` pulled from the corresponding `Pattern` when one is
+/// registered (taint findings fall back to the rule id itself).
+pub fn to_sarif(diags: &[Diag]) -> Value {
+    let mut rules: BTreeMap<&str, Value> = BTreeMap::new();
+    let mut results = Vec::with_capacity(diags.len());
+
+    for d in diags {
+        let description: Cow<str> = patterns::describe(&d.id)
+            .map(|p| p.description.clone())
+            .unwrap_or(Cow::Borrowed(d.id.as_str()));
+        // Taint findings carry a compiler-error-style title ("untrusted data
+        // reaches shell sink"); plain pattern matches fall back to the rule
+        // description, same as `description` above.
+        let message: Cow<str> = d
+            .title
+            .as_deref()
+            .map(Cow::Borrowed)
+            .unwrap_or_else(|| description.clone());
+
+        rules.entry(d.id.as_str()).or_insert_with(|| {
+            let pattern = patterns::describe(&d.id);
+            let mut rule = json!({
+                "id": d.id,
+                "shortDescription": { "text": description },
+                "defaultConfiguration": { "level": sarif_level(d.severity) },
+            });
+            if let Some(p) = pattern {
+                if p.cwe.is_some() || p.owasp.is_some() {
+                    rule.as_object_mut().unwrap().insert(
+                        "properties".to_string(),
+                        json!({ "cwe": p.cwe, "owasp": p.owasp }),
+                    );
+                }
+            }
+            rule
+        });
+
+        let region = span_region(d.line, d.col, d.end_line, d.end_col);
+
+        // Secondary spans (source + intermediate flow steps) become SARIF
+        // `relatedLocations` so a code-scanning UI can show the whole chain
+        // alongside the primary finding at the sink.
+        let related_locations: Vec<Value> = d
+            .spans
+            .iter()
+            .filter(|s| !s.primary)
+            .map(|s| {
+                json!({
+                    "message": { "text": s.label },
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": d.path },
+                        "region": span_region(s.line, s.col, s.end_line, s.end_col),
+                    },
+                })
+            })
+            .collect();
+
+        let mut result = json!({
+            "ruleId": d.id,
+            "level": sarif_level(d.severity),
+            "message": { "text": message },
+            "locations": [{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": d.path },
+                    "region": region,
+                },
+            }],
+        });
+        if !related_locations.is_empty() {
+            result
+                .as_object_mut()
+                .unwrap()
+                .insert("relatedLocations".to_string(), json!(related_locations));
+        }
+
+        // The full source→…→sink chain, as a single-threaded SARIF
+        // `codeFlow` — the standard way code-scanning UIs render a taint
+        // path step-by-step, alongside the flatter `relatedLocations` above.
+        if !d.spans.is_empty() {
+            let thread_flow_locations: Vec<Value> = d
+                .spans
+                .iter()
+                .map(|s| {
+                    json!({
+                        "location": {
+                            "message": { "text": s.label },
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": d.path },
+                                "region": span_region(s.line, s.col, s.end_line, s.end_col),
+                            },
+                        },
+                    })
+                })
+                .collect();
+            result.as_object_mut().unwrap().insert(
+                "codeFlows".to_string(),
+                json!([{ "threadFlows": [{ "locations": thread_flow_locations }] }]),
+            );
+        }
+
+        results.push(result);
+    }
+
+    json!({
+        "version": "2.1.0",
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "nyx",
+                    "rules": rules.into_values().collect::<Vec<_>>(),
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+/// Map our three-tier severity onto a GitHub Actions workflow-command level.
+fn github_annotation_level(sev: Severity) -> &'static str {
+    match sev {
+        Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "notice",
+    }
+}
+
+/// Escape the handful of characters GitHub's workflow-command parser treats
+/// specially inside a `key=value` property, so a rule description
+/// containing e.g. a newline or `%` can't corrupt the annotation.
+fn escape_workflow_property(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Render `diags` as a stream of GitHub Actions workflow commands
+/// (`::error file=...,line=...,col=...,title=<rule id>::<description>`),
+/// one line per finding, so `nyx scan --format github` can run straight in
+/// a workflow step and have findings show up as inline PR annotations
+/// without a separate problem-matcher or wrapper script.
+pub fn to_github_annotations(diags: &[Diag]) -> String {
+    diags
+        .iter()
+        .map(|d| {
+            let level = github_annotation_level(d.severity);
+            let pattern = patterns::describe(&d.id);
+            let description = pattern
+                .map(|p| p.description.clone())
+                .unwrap_or(Cow::Borrowed(d.id.as_str()));
+            let description = match pattern.and_then(|p| p.cwe) {
+                Some(cwe) => Cow::Owned(format!("{description} [{cwe}]")),
+                None => description,
+            };
+
+            format!(
+                "::{level} file={},line={},col={},title={}::{}",
+                escape_workflow_property(&d.path),
+                d.line,
+                d.col,
+                escape_workflow_property(&d.id),
+                escape_workflow_property(&description)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render `diags` as a plain JSON array, one object per finding. Taint
+/// findings additionally carry `title` and an ordered `spans` chain; plain
+/// pattern matches omit both (`title` is `null`, `spans` is empty).
+pub fn to_json(diags: &[Diag]) -> Value {
+    json!(
+        diags
+            .iter()
+            .map(|d| {
+                let pattern = patterns::describe(&d.id);
+                json!({
+                    "path": d.path,
+                    "line": d.line,
+                    "col": d.col,
+                    "severity": d.severity.as_db_str(),
+                    "id": d.id,
+                    "cwe": pattern.and_then(|p| p.cwe),
+                    "owasp": pattern.and_then(|p| p.owasp),
+                    "title": d.title,
+                    "spans": d.spans.iter().map(|s| json!({
+                        "label": s.label,
+                        "primary": s.primary,
+                        "line": s.line,
+                        "col": s.col,
+                        "endLine": s.end_line,
+                        "endCol": s.end_col,
+                    })).collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>()
+    )
+}
+
+/// Render `diags` as line-delimited JSON (one compact object per line,
+/// same shape as [`to_json`]'s array elements) — the format CI log
+/// pipelines and streaming ingestion tools expect instead of one big array,
+/// since each line can be parsed and forwarded independently without
+/// buffering the whole result set.
+pub fn to_json_lines(diags: &[Diag]) -> String {
+    let Value::Array(rows) = to_json(diags) else {
+        unreachable!("to_json always returns a JSON array");
+    };
+    rows.iter()
+        .map(|row| row.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
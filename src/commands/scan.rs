@@ -1,6 +1,7 @@
 pub(crate) use crate::ast::run_rules_on_file;
 use crate::database::index::{Indexer, IssueRow};
 use crate::errors::NyxResult;
+use crate::labels::Cap;
 use crate::patterns::Severity;
 use crate::utils::config::Config;
 use crate::utils::project::get_project_info;
@@ -12,29 +13,125 @@ use r2d2_sqlite::SqliteConnectionManager;
 use rayon::prelude::*;
 use std::collections::BTreeMap;
 use std::path::Path;
+use std::process::exit;
 use std::sync::{Arc, Mutex};
 
 type DynError = Box<dyn std::error::Error + Send + Sync>;
 
-#[derive(Debug)]
+/// One labeled position in a multi-step taint-flow diagnostic: the sink
+/// ("tainted value used here", `primary: true`), the source ("untrusted
+/// data enters here"), and any intermediate assignments/calls the value
+/// passed through on the way there. Positions use the same 1-based
+/// line/col convention as `Diag` itself.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FlowSpan {
+    pub(crate) label: String,
+    pub(crate) primary: bool,
+    pub(crate) line: usize,
+    pub(crate) col: usize,
+    pub(crate) end_line: Option<usize>,
+    pub(crate) end_col: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
 pub struct Diag {
     pub(crate) path: String,
     pub(crate) line: usize,
     pub(crate) col: usize,
     pub(crate) severity: Severity,
     pub(crate) id: String,
+    /// End of the matched node's span (1-based, like `line`/`col`), when the
+    /// diagnostic was built straight from a parsed AST node. Diags
+    /// reconstructed from the SQLite index have no stored end position, so
+    /// this is `None` there.
+    pub(crate) end_line: Option<usize>,
+    pub(crate) end_col: Option<usize>,
+    /// Compiler-error-style title for a taint finding (e.g. "untrusted data
+    /// reaches shell sink"), shown in place of the rule description when
+    /// present. `None` for plain pattern matches and for index-reconstructed
+    /// diags, which fall back to `patterns::describe(&id)` / `id` itself.
+    pub(crate) title: Option<String>,
+    /// Ordered source→…→sink chain behind a taint finding — empty for
+    /// pattern-match diagnostics; diags reconstructed from the index carry
+    /// the spans that were stored with the issue.
+    pub(crate) spans: Vec<FlowSpan>,
+    /// Capability bits in play at this finding's sink (e.g. `SHELL_ESCAPE`
+    /// for a command-injection flow), empty for plain pattern matches that
+    /// never went through the taint engine.
+    pub(crate) caps: Cap,
+}
+
+impl Diag {
+    /// Stable identity for this finding, independent of line/col drift: a
+    /// `blake3` hash of the rule id, path, (for taint findings) every span's
+    /// label in order, and `ordinal` — this finding's rank among every other
+    /// current finding sharing its `(path, id)`, from [`fingerprint_ordinals`].
+    /// Two scans of the same flow through the same rule at the same file
+    /// produce the same fingerprint even when unrelated edits elsewhere in
+    /// the file shifted every line number — something a baseline diff's
+    /// line-drift tolerance alone still can't give it. `ordinal` exists
+    /// because plain pattern matches never populate `spans`: without it,
+    /// every instance of the same rule in the same file would hash
+    /// identically and collide in `diff_against_baseline`'s fingerprint pass.
+    pub(crate) fn fingerprint(&self, ordinal: usize) -> String {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.id.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(self.path.as_bytes());
+        hasher.update(b"\0");
+        hasher.update(&ordinal.to_le_bytes());
+        for span in &self.spans {
+            hasher.update(b"\0");
+            hasher.update(span.label.as_bytes());
+        }
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+/// Ranks each diag in `diags` among others sharing its `(path, id)`, ordered
+/// by `(line, col)` rather than by `diags`' own order — so the assignment is
+/// stable whether `diags` is one file's findings (scan time) or the whole
+/// project's (baseline time), and doesn't depend on the interleaving of
+/// whichever rule engines produced them. Feeds [`Diag::fingerprint`]'s
+/// `ordinal` parameter.
+pub(crate) fn fingerprint_ordinals(diags: &[Diag]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..diags.len()).collect();
+    order.sort_by(|&a, &b| {
+        (diags[a].path.as_str(), diags[a].id.as_str(), diags[a].line, diags[a].col).cmp(&(
+            diags[b].path.as_str(),
+            diags[b].id.as_str(),
+            diags[b].line,
+            diags[b].col,
+        ))
+    });
+
+    let mut ordinals = vec![0usize; diags.len()];
+    let mut counters: BTreeMap<(&str, &str), usize> = BTreeMap::new();
+    for idx in order {
+        let counter = counters
+            .entry((diags[idx].path.as_str(), diags[idx].id.as_str()))
+            .or_insert(0);
+        ordinals[idx] = *counter;
+        *counter += 1;
+    }
+    ordinals
 }
 
 /// Entry point called by the CLI.
+#[allow(clippy::too_many_arguments)]
 pub fn handle(
     path: &str,
     no_index: bool,
     rebuild_index: bool,
     format: String,
+    fix: bool,
+    baseline: Option<String>,
     database_dir: &Path,
     config: &Config,
 ) -> NyxResult<()> {
     let scan_path = Path::new(path).canonicalize()?;
+    let layered_config = config.layered_for_path(&scan_path)?;
+    let config = &layered_config;
     let (project_name, db_path) = get_project_info(&scan_path, database_dir)?;
 
     println!(
@@ -43,6 +140,24 @@ pub fn handle(
         &project_name
     );
 
+    if let Some(name) = baseline {
+        if no_index {
+            return Err("--baseline requires the index (drop --no-index)".into());
+        }
+
+        if rebuild_index || !db_path.exists() {
+            tracing::debug!("Scanning filesystem index filesystem");
+            crate::commands::index::build_index(&project_name, &scan_path, &db_path, config)?;
+        }
+
+        let pool = Indexer::init(&db_path)?;
+        scan_with_index_parallel(&project_name, pool.clone(), config)?;
+
+        let idx = Indexer::from_pool(&project_name, &pool)?;
+        let diff = idx.diff_baseline(&name)?;
+        return report_baseline_diff(&project_name, &name, diff);
+    }
+
     let diags: Vec<Diag> = if no_index {
         scan_filesystem(&scan_path, config)?
     } else {
@@ -57,7 +172,33 @@ pub fn handle(
 
     tracing::debug!("Found {:?} issues.", diags.len());
 
-    if format == "console" || (format.is_empty() && config.output.default_format == "console") {
+    let effective_format = if format.is_empty() {
+        config.output.default_format.as_str()
+    } else {
+        format.as_str()
+    };
+
+    if effective_format == "sarif" {
+        println!("{}", serde_json::to_string_pretty(&crate::commands::report::to_sarif(&diags))?);
+        return Ok(());
+    }
+
+    if effective_format == "json" {
+        println!("{}", serde_json::to_string_pretty(&crate::commands::report::to_json(&diags))?);
+        return Ok(());
+    }
+
+    if effective_format == "ndjson" {
+        println!("{}", crate::commands::report::to_json_lines(&diags));
+        return Ok(());
+    }
+
+    if effective_format == "github" {
+        println!("{}", crate::commands::report::to_github_annotations(&diags));
+        return Ok(());
+    }
+
+    if effective_format == "console" || (format.is_empty() && config.output.default_format == "console") {
         tracing::debug!("Printing to console");
         let mut grouped: BTreeMap<&str, Vec<&Diag>> = BTreeMap::new();
         for d in &diags {
@@ -74,6 +215,51 @@ pub fn handle(
                     d.severity,
                     style(&d.id).bold()
                 );
+
+                if let Some(title) = &d.title {
+                    println!("        {}", style(title).bold());
+                }
+                if let Some(p) = crate::patterns::describe(&d.id) {
+                    if p.cwe.is_some() || p.owasp.is_some() {
+                        println!(
+                            "        {}",
+                            style(
+                                [p.cwe, p.owasp]
+                                    .into_iter()
+                                    .flatten()
+                                    .collect::<Vec<_>>()
+                                    .join("  ")
+                            )
+                            .dim()
+                        );
+                    }
+                }
+                for span in &d.spans {
+                    println!(
+                        "        {:>4}:{:<4}  {} {}",
+                        span.line,
+                        span.col,
+                        if span.primary { "->" } else { "  " },
+                        style(&span.label).dim()
+                    );
+                }
+
+                if let Some(f) = crate::patterns::describe(&d.id).and_then(|p| p.fix.as_ref()) {
+                    if fix {
+                        println!(
+                            "        {} {} → {}",
+                            style("fix:").green(),
+                            style(&f.description).dim(),
+                            style(&f.template).italic()
+                        );
+                    } else {
+                        println!(
+                            "        {} {} (pass --fix to see the suggested rewrite)",
+                            style("hint:").cyan(),
+                            f.description
+                        );
+                    }
+                }
             }
             println!();
         }
@@ -89,6 +275,61 @@ pub fn handle(
     Ok(())
 }
 
+/// Print a baseline diff and exit nonzero iff there are new regressions —
+/// the thing that lets `nyx scan --baseline <name>` act as a CI gate.
+fn report_baseline_diff(
+    project_name: &str,
+    baseline_name: &str,
+    diff: crate::database::index::BaselineDiff,
+) -> NyxResult<()> {
+    if diff.new.is_empty() && diff.fixed.is_empty() {
+        println!(
+            "{} '{}' matches baseline '{}' — no changes.",
+            style("✔").green().bold(),
+            project_name,
+            baseline_name
+        );
+        return Ok(());
+    }
+
+    if !diff.new.is_empty() {
+        println!("{}", style("New issues").red().bold());
+        for d in &diff.new {
+            println!(
+                "  {}:{}:{}  [{}]  {}",
+                d.path,
+                d.line,
+                d.col,
+                d.severity,
+                style(&d.id).bold()
+            );
+        }
+        println!();
+    }
+
+    if !diff.fixed.is_empty() {
+        println!("{}", style("Fixed since baseline").green().bold());
+        for f in &diff.fixed {
+            println!("  {}:{}:{}  [{}]", f.path, f.line, f.col, style(&f.rule_id).dim());
+        }
+        println!();
+    }
+
+    println!(
+        "{} '{}' vs baseline '{}': {} new, {} fixed.",
+        style("note").cyan().bold(),
+        project_name,
+        baseline_name,
+        diff.new.len(),
+        diff.fixed.len()
+    );
+
+    if !diff.new.is_empty() {
+        exit(1);
+    }
+    Ok(())
+}
+
 // --------------------------------------------------------------------------------------------
 // Scanning helpers
 // --------------------------------------------------------------------------------------------
@@ -122,6 +363,7 @@ pub fn scan_with_index_parallel(
     };
 
     let diag_map: DashMap<String, Vec<Diag>> = DashMap::new();
+    let embedder = crate::embed::HashingEmbedder::new(64);
 
     files.into_par_iter().for_each_init(
         || Indexer::from_pool(project, &pool).expect("db pool"),
@@ -131,16 +373,43 @@ pub fn scan_with_index_parallel(
             let mut diags = if needs_scan {
                 let d = run_rules_on_file(&path, cfg).unwrap_or_default();
                 let file_id = idx.upsert_file(&path).unwrap_or_default();
-                idx.replace_issues(
-                    file_id,
-                    d.iter().map(|d| IssueRow {
+                let ordinals = fingerprint_ordinals(&d);
+                let rows: Vec<IssueRow> = d
+                    .iter()
+                    .zip(&ordinals)
+                    .map(|(d, &ordinal)| IssueRow {
                         rule_id: &d.id,
                         severity: d.severity.as_db_str(),
                         line: d.line as i64,
                         col: d.col as i64,
-                    }),
-                )
-                .ok();
+                        caps: d.caps.bits(),
+                        fingerprint: d.fingerprint(ordinal),
+                        spans_json: serde_json::to_string(&d.spans).unwrap_or_default(),
+                    })
+                    .collect();
+                idx.replace_issues(file_id, rows).ok();
+
+                // Only regenerate summaries for functions whose content
+                // hash actually changed — pattern matching above still
+                // runs over the whole file (tree-sitter queries are
+                // already a single cheap pass over the tree), but summary
+                // generation is the part whose cost this index is meant
+                // to let us skip for untouched functions.
+                if let Ok(changed) =
+                    crate::functions::diff_and_store_function_hashes(&path, idx, file_id)
+                {
+                    for (hash, span) in changed {
+                        idx.upsert_summary(
+                            project,
+                            &path,
+                            &hash,
+                            &crate::summary::FuncSummary { name: span.name },
+                            &embedder,
+                        )
+                        .ok();
+                    }
+                }
+
                 d
             } else {
                 idx.get_issues_from_file(&path).unwrap_or_default()
@@ -0,0 +1,129 @@
+//! `nyx test <dir>` — a regression runner for rule authors.
+//!
+//! Fixtures annotate the line *after* them with `// EXPECT: <rule-id>` (a
+//! finding must land there) or `// NO-FINDING` (nothing must land there).
+//! This reuses the normal scanning path and just compares its output
+//! against those inline expectations instead of printing them.
+
+use crate::ast::run_rules_on_file;
+use crate::errors::NyxResult;
+use crate::utils::Config;
+use console::style;
+use ignore::WalkBuilder;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Default)]
+struct Expectations {
+    /// line → rule ids that must be reported on that line.
+    expect: BTreeMap<usize, Vec<String>>,
+    /// lines that must produce no finding at all.
+    no_finding: Vec<usize>,
+}
+
+fn parse_expectations(src: &str) -> Expectations {
+    let mut out = Expectations::default();
+
+    for (i, line) in src.lines().enumerate() {
+        let lineno = i + 1;
+        let Some(comment) = line.split("//").nth(1) else {
+            continue;
+        };
+        let comment = comment.trim();
+
+        if let Some(rule) = comment.strip_prefix("EXPECT:") {
+            // Annotation applies to the *next* source line.
+            out.expect
+                .entry(lineno + 1)
+                .or_default()
+                .push(rule.trim().to_owned());
+        } else if comment == "NO-FINDING" {
+            out.no_finding.push(lineno + 1);
+        }
+    }
+
+    out
+}
+
+/// Run every fixture under `dir` and report EXPECT/NO-FINDING mismatches.
+/// Returns `Ok(())` but exits the process with a nonzero code on failure,
+/// matching how the other subcommands in this module report terminal status.
+pub fn handle(dir: &str, config: &Config) -> NyxResult<()> {
+    let root = Path::new(dir).canonicalize()?;
+    let mut total_missing = 0usize;
+    let mut total_unexpected = 0usize;
+    let mut total_fixtures = 0usize;
+
+    for entry in WalkBuilder::new(&root).hidden(false).build() {
+        let entry = entry.map_err(|e| crate::errors::NyxError::Msg(e.to_string()))?;
+        if !entry.file_type().map(|ft| ft.is_file()).unwrap_or(false) {
+            continue;
+        }
+        let path = entry.path();
+
+        let src = match std::fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(_) => continue, // skip binary/unreadable files
+        };
+        if !src.contains("EXPECT:") && !src.contains("NO-FINDING") {
+            continue;
+        }
+        total_fixtures += 1;
+
+        let expectations = parse_expectations(&src);
+        let diags = run_rules_on_file(path, config)?;
+
+        let mut found_on_line: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+        for d in &diags {
+            found_on_line.entry(d.line).or_default().push(&d.id);
+        }
+
+        println!("{}", style(path.display()).blue().underlined());
+
+        for (&line, rules) in &expectations.expect {
+            let found = found_on_line.get(&line).cloned().unwrap_or_default();
+            for rule in rules {
+                if found.contains(&rule.as_str()) {
+                    println!("  {} line {line}: {rule}", style("ok").green());
+                } else {
+                    total_missing += 1;
+                    println!(
+                        "  {} line {line}: expected `{rule}`, got {:?}",
+                        style("MISSING").red().bold(),
+                        found
+                    );
+                }
+            }
+        }
+
+        for &line in &expectations.no_finding {
+            if let Some(found) = found_on_line.get(&line) {
+                total_unexpected += found.len();
+                println!(
+                    "  {} line {line}: unexpected finding(s) {:?}",
+                    style("UNEXPECTED").red().bold(),
+                    found
+                );
+            } else {
+                println!("  {} line {line}: no finding (as expected)", style("ok").green());
+            }
+        }
+    }
+
+    println!();
+    if total_missing == 0 && total_unexpected == 0 {
+        println!(
+            "{} {} fixture(s) matched their EXPECT/NO-FINDING annotations.",
+            style("✔").green().bold(),
+            total_fixtures
+        );
+    } else {
+        println!(
+            "{} {total_missing} missing, {total_unexpected} unexpected, across {total_fixtures} fixture(s).",
+            style("✖").red().bold()
+        );
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
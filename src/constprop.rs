@@ -0,0 +1,438 @@
+//! Forward constant-propagation pass over the same `Cfg` the taint analysis
+//! (`taint.rs`) walks, used to catch `const-index-out-of-range` and
+//! `const-integer-overflow` findings that don't need real taint tracking —
+//! just knowing a handful of variables always hold one literal value.
+//!
+//! Unlike `taint::analyse_file`'s worklist fixpoint (which revisits a node
+//! every time a back-edge delivers a new state), this is a single pass over
+//! the graph in topological order with `EdgeKind::Back` edges removed: any
+//! variable a loop body reassigns is widened straight to `Top` at the loop
+//! header instead of being iterated to a fixpoint, so the whole pass stays
+//! O(nodes) no matter how many times a loop would otherwise need revisiting.
+
+use crate::cfg::{binary_operator_text, text_of, Cfg, EdgeKind};
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::Direction;
+use std::collections::{HashMap, HashSet, VecDeque};
+use tree_sitter::{Node, Tree};
+
+/// Flat lattice for one variable's value at one program point: no info yet
+/// (`Bottom`), exactly one literal value on every path seen so far
+/// (`Const`), or two-or-more distinct values / genuinely unknown (`Top`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstVal {
+    Bottom,
+    Const(i64),
+    Top,
+}
+
+impl ConstVal {
+    /// Meet at a CFG join point: agrees on `Const(n)` only when both sides
+    /// do, `Bottom` is the identity (a predecessor that never mentioned the
+    /// variable contributes no information), anything else widens to `Top`.
+    fn meet(self, other: ConstVal) -> ConstVal {
+        use ConstVal::*;
+        match (self, other) {
+            (Bottom, x) | (x, Bottom) => x,
+            (Const(a), Const(b)) if a == b => Const(a),
+            _ => Top,
+        }
+    }
+}
+
+/// One `const-index-out-of-range` / `const-integer-overflow` finding, given
+/// back as a raw byte span rather than a `Diag` — `ast.rs` already knows how
+/// to turn a byte span into a `Diag` (see `taint_flow_spans`/`byte_offset_to_point`)
+/// and this module has no reason to depend on `commands::scan`.
+#[derive(Debug, Clone)]
+pub struct ConstFinding {
+    pub id: &'static str,
+    pub span: (usize, usize),
+}
+
+#[derive(Default, Clone)]
+struct State {
+    consts: HashMap<String, ConstVal>,
+    lens: HashMap<String, ConstVal>,
+}
+
+/// Topological order over `cfg` with `EdgeKind::Back` edges removed, so a
+/// loop header is visited before its body and the body is never revisited.
+/// Falls back to plain node order in the (shouldn't-happen) case that
+/// non-back edges alone still contain a cycle.
+fn topo_order_excluding_back(cfg: &Cfg) -> Vec<NodeIndex> {
+    let mut g: petgraph::graph::DiGraph<(), ()> =
+        petgraph::graph::DiGraph::with_capacity(cfg.node_count(), cfg.edge_count());
+    for _ in cfg.node_indices() {
+        g.add_node(());
+    }
+    for e in cfg.edge_references() {
+        if *e.weight() != EdgeKind::Back {
+            g.add_edge(e.source(), e.target(), ());
+        }
+    }
+    petgraph::algo::toposort(&g, None).unwrap_or_else(|_| cfg.node_indices().collect())
+}
+
+/// Nodes on some path from `header` forward to `src` without leaving
+/// through a repeated visit to `header` — i.e. the natural loop body that
+/// the back-edge `src -> header` closes.
+fn loop_body_nodes(cfg: &Cfg, header: NodeIndex, src: NodeIndex) -> HashSet<NodeIndex> {
+    let mut fwd = HashSet::new();
+    let mut q = VecDeque::from([header]);
+    fwd.insert(header);
+    while let Some(n) = q.pop_front() {
+        for e in cfg.edges(n) {
+            if *e.weight() != EdgeKind::Back && fwd.insert(e.target()) {
+                q.push_back(e.target());
+            }
+        }
+    }
+
+    let mut bwd = HashSet::new();
+    let mut q = VecDeque::from([src]);
+    bwd.insert(src);
+    while let Some(n) = q.pop_front() {
+        for e in cfg.edges_directed(n, Direction::Incoming) {
+            if *e.weight() != EdgeKind::Back && bwd.insert(e.source()) {
+                q.push_back(e.source());
+            }
+        }
+    }
+
+    fwd.intersection(&bwd).copied().collect()
+}
+
+/// Variables whose value a loop body may change, keyed by the loop header —
+/// forced to `Top` there instead of folding the back-edge into a fixpoint.
+fn widen_targets(cfg: &Cfg) -> HashMap<NodeIndex, HashSet<String>> {
+    let mut out: HashMap<NodeIndex, HashSet<String>> = HashMap::new();
+    for e in cfg.edge_references() {
+        if *e.weight() == EdgeKind::Back {
+            let (src, header) = (e.source(), e.target());
+            let vars = out.entry(header).or_default();
+            for n in loop_body_nodes(cfg, header, src) {
+                if let Some(d) = &cfg[n].defines {
+                    vars.insert(d.clone());
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Strip underscores and a trailing integer-type suffix (`5i32`, `0xFFu8`,
+/// …), then parse the remaining literal, honouring `0x`/`0o`/`0b` prefixes.
+fn parse_int_literal(text: &str) -> Option<i64> {
+    let cleaned: String = text.chars().filter(|c| *c != '_').collect();
+    const SUFFIXES: &[&str] = &[
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+    let mut body = cleaned.as_str();
+    for suf in SUFFIXES {
+        if let Some(stripped) = body.strip_suffix(suf) {
+            body = stripped;
+            break;
+        }
+    }
+    if let Some(hex) = body.strip_prefix("0x") {
+        return i64::from_str_radix(hex, 16).ok();
+    }
+    if let Some(oct) = body.strip_prefix("0o") {
+        return i64::from_str_radix(oct, 8).ok();
+    }
+    if let Some(bin) = body.strip_prefix("0b") {
+        return i64::from_str_radix(bin, 2).ok();
+    }
+    body.parse::<i64>().ok()
+}
+
+/// `(min, max)` for a Rust integer type name, widened to `i128` so the
+/// bound check below never itself overflows.
+fn int_bounds(name: &str) -> Option<(i128, i128)> {
+    Some(match name.trim() {
+        "i8" => (i8::MIN as i128, i8::MAX as i128),
+        "i16" => (i16::MIN as i128, i16::MAX as i128),
+        "i32" => (i32::MIN as i128, i32::MAX as i128),
+        "i64" | "isize" => (i64::MIN as i128, i64::MAX as i128),
+        "u8" => (u8::MIN as i128, u8::MAX as i128),
+        "u16" => (u16::MIN as i128, u16::MAX as i128),
+        "u32" => (u32::MIN as i128, u32::MAX as i128),
+        "u64" | "usize" => (u64::MIN as i128, u64::MAX as i128),
+        _ => return None,
+    })
+}
+
+/// The width to check a `let`/assignment's arithmetic against: its own `: iN`
+/// annotation when present, else `i32` — Rust's default integer type for an
+/// unsuffixed literal, and the most common case for unannotated bindings.
+fn declared_width(ast_node: Node, code: &[u8]) -> (i128, i128) {
+    ast_node
+        .child_by_field_name("type")
+        .and_then(|ty| text_of(ty, code))
+        .and_then(|t| int_bounds(&t))
+        .unwrap_or_else(|| int_bounds("i32").unwrap())
+}
+
+fn rhs_of(n: Node) -> Option<Node> {
+    match n.kind() {
+        "let_declaration" => n.child_by_field_name("value"),
+        "assignment_expression" => n.child_by_field_name("right"),
+        _ => None,
+    }
+}
+
+/// Evaluate `n` against the current `consts` environment, returning `Top`
+/// for anything this pass doesn't model (function calls, field access, …).
+fn eval_expr_const(n: Node, code: &[u8], consts: &HashMap<String, ConstVal>) -> ConstVal {
+    match n.kind() {
+        "integer_literal" => text_of(n, code)
+            .and_then(|t| parse_int_literal(&t))
+            .map(ConstVal::Const)
+            .unwrap_or(ConstVal::Top),
+        "identifier" => consts.get(n.utf8_text(code).unwrap_or("")).copied().unwrap_or(ConstVal::Top),
+        "parenthesized_expression" => n
+            .named_child(0)
+            .map(|c| eval_expr_const(c, code, consts))
+            .unwrap_or(ConstVal::Top),
+        "unary_expression" => {
+            let op = n.child_by_field_name("operator").and_then(|o| text_of(o, code));
+            let arg = n.child_by_field_name("argument");
+            match (op.as_deref(), arg) {
+                (Some("-"), Some(a)) => match eval_expr_const(a, code, consts) {
+                    ConstVal::Const(v) => ConstVal::Const(-v),
+                    other => other,
+                },
+                _ => ConstVal::Top,
+            }
+        }
+        "binary_expression" => {
+            let (Some(l), Some(r)) = (
+                n.child_by_field_name("left"),
+                n.child_by_field_name("right"),
+            ) else {
+                return ConstVal::Top;
+            };
+            let (ConstVal::Const(a), ConstVal::Const(b)) =
+                (eval_expr_const(l, code, consts), eval_expr_const(r, code, consts))
+            else {
+                return ConstVal::Top;
+            };
+            let result = match binary_operator_text(n, code).as_deref() {
+                Some("+") => (a as i128).checked_add(b as i128),
+                Some("-") => (a as i128).checked_sub(b as i128),
+                Some("*") => (a as i128).checked_mul(b as i128),
+                Some("/") if b != 0 => (a as i128).checked_div(b as i128),
+                Some("%") if b != 0 => (a as i128).checked_rem(b as i128),
+                _ => None,
+            };
+            match result.and_then(|r| i64::try_from(r).ok()) {
+                Some(v) => ConstVal::Const(v),
+                None => ConstVal::Top,
+            }
+        }
+        _ => ConstVal::Top,
+    }
+}
+
+/// `[e1, e2, …]` / `[value; N]` length, when it resolves to a `Const`.
+fn array_len(n: Node, code: &[u8], consts: &HashMap<String, ConstVal>) -> ConstVal {
+    if n.kind() != "array_expression" {
+        return ConstVal::Top;
+    }
+    match n.child_by_field_name("length") {
+        Some(len_node) => eval_expr_const(len_node, code, consts),
+        None => ConstVal::Const(n.named_child_count() as i64),
+    }
+}
+
+fn walk_for_index_exprs(
+    n: Node,
+    code: &[u8],
+    consts: &HashMap<String, ConstVal>,
+    lens: &HashMap<String, ConstVal>,
+    out: &mut Vec<ConstFinding>,
+) {
+    if n.kind() == "index_expression" {
+        if let (Some(value), Some(index)) =
+            (n.child_by_field_name("value"), n.child_by_field_name("index"))
+        {
+            if let (Some(arr_name), ConstVal::Const(idx)) =
+                (text_of(value, code), eval_expr_const(index, code, consts))
+            {
+                if let Some(ConstVal::Const(size)) = lens.get(&arr_name).copied() {
+                    if idx < 0 || idx >= size {
+                        out.push(ConstFinding {
+                            id: "const-index-out-of-range",
+                            span: (n.start_byte(), n.end_byte()),
+                        });
+                    }
+                }
+            }
+        }
+    }
+    let mut cursor = n.walk();
+    for c in n.children(&mut cursor) {
+        walk_for_index_exprs(c, code, consts, lens, out);
+    }
+}
+
+/// Run the constant-propagation pass over `cfg` and report every
+/// `const-index-out-of-range` / `const-integer-overflow` finding.
+pub(crate) fn analyse_constants(tree: &Tree, cfg: &Cfg, code: &[u8]) -> Vec<ConstFinding> {
+    let widen = widen_targets(cfg);
+    let order = topo_order_excluding_back(cfg);
+    let mut out_state: HashMap<NodeIndex, State> = HashMap::new();
+    let mut findings = Vec::new();
+
+    for node in order {
+        let mut consts: HashMap<String, ConstVal> = HashMap::new();
+        let mut lens: HashMap<String, ConstVal> = HashMap::new();
+
+        for e in cfg.edges_directed(node, Direction::Incoming) {
+            if *e.weight() == EdgeKind::Back {
+                continue;
+            }
+            if let Some(pred) = out_state.get(&e.source()) {
+                for (k, v) in &pred.consts {
+                    let merged = consts.get(k).copied().unwrap_or(ConstVal::Bottom).meet(*v);
+                    consts.insert(k.clone(), merged);
+                }
+                for (k, v) in &pred.lens {
+                    let merged = lens.get(k).copied().unwrap_or(ConstVal::Bottom).meet(*v);
+                    lens.insert(k.clone(), merged);
+                }
+            }
+        }
+
+        if let Some(vars) = widen.get(&node) {
+            for v in vars {
+                consts.insert(v.clone(), ConstVal::Top);
+                lens.insert(v.clone(), ConstVal::Top);
+            }
+        }
+
+        let span = cfg[node].span;
+        if let Some(ast_node) = tree.root_node().descendant_for_byte_range(span.0, span.1) {
+            walk_for_index_exprs(ast_node, code, &consts, &lens, &mut findings);
+
+            if let Some(def) = &cfg[node].defines {
+                if let Some(rhs) = rhs_of(ast_node) {
+                    let val = eval_expr_const(rhs, code, &consts);
+                    if let ConstVal::Const(n) = val {
+                        if rhs.kind() == "binary_expression" {
+                            let (lo, hi) = declared_width(ast_node, code);
+                            if (n as i128) < lo || (n as i128) > hi {
+                                findings.push(ConstFinding {
+                                    id: "const-integer-overflow",
+                                    span: (rhs.start_byte(), rhs.end_byte()),
+                                });
+                            }
+                        }
+                    }
+                    consts.insert(def.clone(), val);
+                    if rhs.kind() == "array_expression" {
+                        lens.insert(def.clone(), array_len(rhs, code, &consts));
+                    }
+                }
+            }
+        }
+
+        out_state.insert(node, State { consts, lens });
+    }
+
+    findings
+}
+
+#[test]
+fn flags_out_of_range_constant_index() {
+    use tree_sitter::Language;
+    let src = br#"
+        fn main() {
+            let arr = [1, 2, 3];
+            let idx = 5;
+            let x = arr[idx];
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (cfg, _entry, _summaries) = crate::cfg::build_cfg(&tree, src, "rust");
+    let findings = analyse_constants(&tree, &cfg, src);
+
+    assert!(findings.iter().any(|f| f.id == "const-index-out-of-range"));
+}
+
+#[test]
+fn in_range_constant_index_is_not_flagged() {
+    use tree_sitter::Language;
+    let src = br#"
+        fn main() {
+            let arr = [1, 2, 3];
+            let idx = 1;
+            let x = arr[idx];
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (cfg, _entry, _summaries) = crate::cfg::build_cfg(&tree, src, "rust");
+    let findings = analyse_constants(&tree, &cfg, src);
+
+    assert!(findings.is_empty());
+}
+
+#[test]
+fn flags_declared_width_overflow() {
+    use tree_sitter::Language;
+    let src = br#"
+        fn main() {
+            let a: i8 = 100;
+            let b: i8 = 100;
+            let c: i8 = a + b;
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (cfg, _entry, _summaries) = crate::cfg::build_cfg(&tree, src, "rust");
+    let findings = analyse_constants(&tree, &cfg, src);
+
+    assert!(findings.iter().any(|f| f.id == "const-integer-overflow"));
+}
+
+#[test]
+fn widens_loop_modified_index_instead_of_flagging() {
+    use tree_sitter::Language;
+    let src = br#"
+        fn main() {
+            let arr = [1, 2, 3];
+            let mut idx = 0;
+            while idx < 10 {
+                idx = idx + 1;
+            }
+            let x = arr[idx];
+        }"#;
+
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src as &[u8], None).unwrap();
+
+    let (cfg, _entry, _summaries) = crate::cfg::build_cfg(&tree, src, "rust");
+    // `idx` is reassigned inside the loop, so it's widened to `Top` at the
+    // header rather than reported as a false-positive out-of-range index.
+    let findings = analyse_constants(&tree, &cfg, src);
+    assert!(!findings.iter().any(|f| f.id == "const-index-out-of-range"));
+}
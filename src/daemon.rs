@@ -0,0 +1,273 @@
+//! Long-running watch mode: keep per-file diagnostics cached in memory and
+//! only re-run analysis on files whose mtime actually moved, instead of
+//! `nyx scan`'s one-shot walk-everything-then-exit. `nyx watch --lsp` hands
+//! the exact same incremental rescan off to `crate::lsp` instead of
+//! printing deltas to the console, so editors see diagnostics update live.
+
+use crate::ast::run_rules_on_bytes;
+use crate::commands::scan::Diag;
+use crate::errors::NyxResult;
+use crate::utils::Config;
+use crate::walk::spawn_senders;
+use console::style;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// How often the watch loop re-walks the tree looking for changed mtimes.
+/// Real filesystem-event notification (inotify/FSEvents/…) would push
+/// changes instead of this polling, but re-walking via the same `ignore`
+/// crate `spawn_senders` already uses is cheap enough for interactive use
+/// and keeps this mode free of a new OS-level dependency.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// One file's cached analysis: the diagnostics found there last time, and
+/// the mtime that result is valid for, so a later pass can tell "this file
+/// hasn't changed" without re-parsing it.
+struct CachedFile {
+    mtime: SystemTime,
+    diags: Vec<Diag>,
+}
+
+/// `(line, col, rule id)` — enough to tell two diagnostics apart for the
+/// purposes of an added/resolved diff; a taint finding's `spans` can shift
+/// slightly between rescans of the *same* underlying issue (e.g. an
+/// intermediate assignment renumbers), so the key deliberately doesn't
+/// include them.
+fn diag_key(d: &Diag) -> (usize, usize, &str) {
+    (d.line, d.col, d.id.as_str())
+}
+
+/// Re-run analysis on `path` if its mtime moved since the cached entry (or
+/// it has none yet). Returns the freshly computed diagnostics, or `None` if
+/// nothing needed rescanning. A file that's vanished or become unreadable
+/// has its cache entry dropped and also reports `None` — callers that need
+/// to flag files that disappeared entirely reconcile against the full
+/// directory walk instead (see `handle`'s "gone" pass).
+fn rescan_one(
+    path: &Path,
+    cfg: &Config,
+    cache: &mut HashMap<PathBuf, CachedFile>,
+) -> NyxResult<Option<Vec<Diag>>> {
+    let mtime = match std::fs::metadata(path).and_then(|m| m.modified()) {
+        Ok(m) => m,
+        Err(_) => {
+            cache.remove(path);
+            return Ok(None);
+        }
+    };
+
+    if cache.get(path).is_some_and(|c| c.mtime == mtime) {
+        return Ok(None);
+    }
+
+    let bytes = std::fs::read(path)?;
+    let diags = run_rules_on_bytes(path, &bytes, cfg)?;
+    cache.insert(
+        path.to_path_buf(),
+        CachedFile {
+            mtime,
+            diags: diags.clone(),
+        },
+    );
+    Ok(Some(diags))
+}
+
+/// Which diags in `new` weren't present in `old` (added) and which in `old`
+/// are gone from `new` (removed), compared by [`diag_key`] — split out of
+/// [`report_delta`] so the add/remove set itself is unit-testable without
+/// capturing console output.
+fn diff_diags<'a>(old: &'a [Diag], new: &'a [Diag]) -> (Vec<&'a Diag>, Vec<&'a Diag>) {
+    let old_keys: HashSet<_> = old.iter().map(diag_key).collect();
+    let new_keys: HashSet<_> = new.iter().map(diag_key).collect();
+
+    let added = new
+        .iter()
+        .filter(|d| !old_keys.contains(&diag_key(d)))
+        .collect();
+    let removed = old
+        .iter()
+        .filter(|d| !new_keys.contains(&diag_key(d)))
+        .collect();
+    (added, removed)
+}
+
+/// Print only what changed between `old` and `new` for `path`: findings
+/// that appeared since the last scan (`+`) and ones that no longer
+/// reproduce (`-`) — the incremental equivalent of `nyx scan`'s full-dump
+/// console output.
+fn report_delta(path: &Path, old: &[Diag], new: &[Diag]) {
+    let (added, removed) = diff_diags(old, new);
+
+    for d in added {
+        println!(
+            "{} {}:{}:{}  [{}]  {}",
+            style("+").green().bold(),
+            path.display(),
+            d.line,
+            d.col,
+            d.severity,
+            style(&d.id).bold()
+        );
+    }
+    for d in removed {
+        println!(
+            "{} {}:{}:{}  [{}]  {}",
+            style("-").red().bold(),
+            path.display(),
+            d.line,
+            d.col,
+            d.severity,
+            style(&d.id).dim()
+        );
+    }
+}
+
+/// Walk `root` once, analysing every reachable file and seeding the cache
+/// subsequent passes rescan from.
+fn initial_scan(root: &Path, cfg: &Config) -> NyxResult<HashMap<PathBuf, CachedFile>> {
+    let rx = spawn_senders(root, cfg);
+    let mut cache = HashMap::new();
+
+    for batch in rx {
+        for path in batch {
+            rescan_one(&path, cfg, &mut cache)?;
+        }
+    }
+
+    Ok(cache)
+}
+
+/// Entry point called by the CLI for `nyx watch`. Blocks forever, polling
+/// `scan_path` for changes until the process is killed.
+pub fn handle(path: &str, lsp: bool, config: &Config) -> NyxResult<()> {
+    let scan_path = Path::new(path).canonicalize()?;
+    let layered_config = config.layered_for_path(&scan_path)?;
+    let cfg = &layered_config;
+
+    if lsp {
+        return crate::lsp::run_server(cfg);
+    }
+
+    println!(
+        "{} watching {} for changes (Ctrl+C to stop)…\n",
+        style("nyx watch").green().bold(),
+        scan_path.display()
+    );
+
+    let mut cache = initial_scan(&scan_path, cfg)?;
+    let mut paths: Vec<PathBuf> = cache.keys().cloned().collect();
+    paths.sort();
+    for path in &paths {
+        if let Some(c) = cache.get(path) {
+            report_delta(path, &[], &c.diags);
+        }
+    }
+
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+
+        let rx = spawn_senders(&scan_path, cfg);
+        let mut seen: HashSet<PathBuf> = HashSet::new();
+
+        for batch in rx {
+            for path in batch {
+                seen.insert(path.clone());
+                let old_diags = cache.get(&path).map(|c| c.diags.clone()).unwrap_or_default();
+                if let Some(new_diags) = rescan_one(&path, cfg, &mut cache)? {
+                    report_delta(&path, &old_diags, &new_diags);
+                }
+            }
+        }
+
+        let gone: Vec<PathBuf> = cache
+            .keys()
+            .filter(|p| !seen.contains(*p))
+            .cloned()
+            .collect();
+        for path in gone {
+            if let Some(old) = cache.remove(&path) {
+                report_delta(&path, &old.diags, &[]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+fn mk_diag(id: &str, line: usize) -> Diag {
+    Diag {
+        path: "test.rs".to_string(),
+        line,
+        col: 1,
+        severity: crate::patterns::Severity::High,
+        id: id.to_string(),
+        end_line: None,
+        end_col: None,
+        title: None,
+        spans: Vec::new(),
+        caps: crate::labels::Cap::empty(),
+    }
+}
+
+#[test]
+fn rescan_one_returns_none_until_mtime_changes() {
+    let td = tempfile::tempdir().unwrap();
+    let file = td.path().join("sample.txt");
+    std::fs::write(&file, "hello").unwrap();
+
+    let cfg = Config::default();
+    let mut cache = HashMap::new();
+
+    let first = rescan_one(&file, &cfg, &mut cache).unwrap();
+    assert!(first.is_some(), "an uncached file always has work to do");
+
+    let second = rescan_one(&file, &cfg, &mut cache).unwrap();
+    assert!(
+        second.is_none(),
+        "mtime unchanged since caching — nothing to rescan"
+    );
+
+    let bumped = cache.get(&file).unwrap().mtime + Duration::from_secs(5);
+    std::fs::File::open(&file).unwrap().set_modified(bumped).unwrap();
+
+    let third = rescan_one(&file, &cfg, &mut cache).unwrap();
+    assert!(third.is_some(), "a later mtime means the cache entry is stale");
+}
+
+#[test]
+fn rescan_one_drops_cache_entry_when_file_disappears() {
+    let td = tempfile::tempdir().unwrap();
+    let file = td.path().join("gone.txt");
+    std::fs::write(&file, "hello").unwrap();
+
+    let cfg = Config::default();
+    let mut cache = HashMap::new();
+    rescan_one(&file, &cfg, &mut cache).unwrap();
+    assert!(cache.contains_key(&file));
+
+    std::fs::remove_file(&file).unwrap();
+    let result = rescan_one(&file, &cfg, &mut cache).unwrap();
+    assert!(result.is_none());
+    assert!(!cache.contains_key(&file));
+}
+
+#[test]
+fn diff_diags_reports_added_and_removed_by_key_not_by_position() {
+    let old = vec![mk_diag("shell_injection", 10), mk_diag("sql_injection", 20)];
+    let new = vec![mk_diag("shell_injection", 10), mk_diag("xss", 30)];
+
+    let (added, removed) = diff_diags(&old, &new);
+
+    assert_eq!(added.len(), 1);
+    assert_eq!(added[0].id, "xss");
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed[0].id, "sql_injection");
+}
+
+#[test]
+fn diff_diags_is_empty_when_nothing_changed() {
+    let diags = vec![mk_diag("shell_injection", 10)];
+    let (added, removed) = diff_diags(&diags, &diags);
+    assert!(added.is_empty());
+    assert!(removed.is_empty());
+}
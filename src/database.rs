@@ -1,15 +1,22 @@
 pub mod index {
-    use crate::commands::scan::Diag;
-    use crate::errors::NyxResult;
+    use crate::commands::scan::{Diag, FlowSpan};
+    use crate::embed::Embedder;
+    use crate::errors::{NyxError, NyxResult};
+    use crate::labels::Cap;
     use crate::patterns::Severity;
+    use crate::summary::FuncSummary;
+    use crate::utils::query_lang::{self, Expr, IssueRecord};
     use r2d2::{Pool, PooledConnection};
     use r2d2_sqlite::SqliteConnectionManager;
     use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
+    use std::cmp::Reverse;
+    use std::collections::{BinaryHeap, HashMap, HashSet};
     use std::fs;
     use std::ops::Deref;
     use std::path::{Path, PathBuf};
     use std::str::FromStr;
-    use std::sync::Arc;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::{Arc, Mutex};
     use std::time::{SystemTime, UNIX_EPOCH};
 
     /// DB schema (foreign‑keys enabled).
@@ -27,13 +34,16 @@ pub mod index {
         );
 
         CREATE TABLE IF NOT EXISTS issues (
-            file_id    INTEGER NOT NULL
-                              REFERENCES files(id)
-                              ON DELETE CASCADE,
-            rule_id    TEXT    NOT NULL,
-            severity   TEXT    NOT NULL,
-            line       INTEGER NOT NULL,
-            col        INTEGER NOT NULL,
+            file_id     INTEGER NOT NULL
+                               REFERENCES files(id)
+                               ON DELETE CASCADE,
+            rule_id     TEXT    NOT NULL,
+            severity    TEXT    NOT NULL,
+            line        INTEGER NOT NULL,
+            col         INTEGER NOT NULL,
+            caps        INTEGER NOT NULL DEFAULT 0,
+            fingerprint TEXT    NOT NULL DEFAULT '',
+            spans_json  TEXT    NOT NULL DEFAULT '[]',
             PRIMARY KEY (file_id, rule_id, line, col)
         );
 
@@ -43,10 +53,164 @@ pub mod index {
             name        TEXT NOT NULL,
             lang        TEXT NOT NULL,
             summary     TEXT NOT NULL,
-            updated_at  INTEGER NOT NULL,
+            embedding   BLOB NOT NULL,
+            dim         INTEGER NOT NULL,
+            updated_at  INTEGER NOT NULL
+        );
+
+        CREATE TABLE IF NOT EXISTS baselines (
+            id         INTEGER PRIMARY KEY AUTOINCREMENT,
+            project    TEXT    NOT NULL,
+            name       TEXT    NOT NULL,
+            created_at INTEGER NOT NULL,
+            UNIQUE(project, name)
+        );
+
+        CREATE TABLE IF NOT EXISTS file_functions (
+            file_id    INTEGER NOT NULL
+                               REFERENCES files(id)
+                               ON DELETE CASCADE,
+            fn_hash    TEXT    NOT NULL,
+            name       TEXT    NOT NULL,
+            start_line INTEGER NOT NULL,
+            end_line   INTEGER NOT NULL,
+            PRIMARY KEY (file_id, fn_hash)
+        );
+
+        CREATE TABLE IF NOT EXISTS baseline_issues (
+            baseline_id INTEGER NOT NULL
+                                REFERENCES baselines(id)
+                                ON DELETE CASCADE,
+            path        TEXT    NOT NULL,
+            rule_id     TEXT    NOT NULL,
+            line        INTEGER NOT NULL,
+            col         INTEGER NOT NULL,
+            fingerprint TEXT    NOT NULL DEFAULT ''
         );
     "#;
 
+    /// How many lines a finding may have drifted and still count as "the
+    /// same issue" when diffing against a baseline — tolerates refactors
+    /// that shift line numbers without changing the underlying code.
+    const BASELINE_LINE_DRIFT: i64 = 3;
+
+    /// One finding recorded in a baseline snapshot. Unlike [`Diag`], this has
+    /// no severity — a fixed finding was never re-scanned, so we only know
+    /// what the baseline said about it.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct BaselineFinding {
+        pub path: String,
+        pub rule_id: String,
+        pub line: i64,
+        pub col: i64,
+        /// [`Diag::fingerprint`]-style stable identity, empty for a baseline
+        /// snapshotted before fingerprints existed. Falls back to the
+        /// line-drift matching below when empty.
+        pub fingerprint: String,
+    }
+
+    /// Result of diffing the current issue set against a named baseline.
+    #[derive(Debug, Default)]
+    pub struct BaselineDiff {
+        /// Findings present now but absent from the baseline — regressions.
+        pub new: Vec<Diag>,
+        /// Findings in the baseline that no longer show up — fixed.
+        pub fixed: Vec<BaselineFinding>,
+    }
+
+    /// Match `current` findings against a `baseline` snapshot: fingerprint
+    /// matches first (stable across line drift entirely — see
+    /// [`Diag::fingerprint`]), then exact `(path, rule_id, line, col)`
+    /// matches among whatever's left, then a fuzzy pass on `(path, rule_id)`
+    /// within [`BASELINE_LINE_DRIFT`] lines to tolerate line drift from
+    /// unrelated edits. Anything left over in `current` is new; anything
+    /// left over in `baseline` is fixed.
+    ///
+    /// The exact-match phase is the bulk of the work on an unchanged
+    /// codebase (tens of thousands of findings that haven't moved at all),
+    /// so it's computed as a `(path, rule_id, line, col)` multiset — a
+    /// `HashMap` of baseline row indices grouped by that key, consumed one
+    /// index per match — rather than the row-by-row scan the fuzzy phase
+    /// below still needs. A multiset rather than a presence set matters:
+    /// two findings that legitimately land on the identical location (the
+    /// same rule firing twice on the same line/col)
+    /// must pair off one-for-one instead of all matching against a single
+    /// shared "present" bit, which would miscount "fixed"/"new".
+    fn diff_against_baseline(current: Vec<Diag>, baseline: Vec<BaselineFinding>) -> BaselineDiff {
+        let mut consumed = vec![false; baseline.len()];
+
+        // Fingerprint pass: a baseline row with a non-empty fingerprint
+        // (i.e. snapshotted after this field was introduced) is considered
+        // matched the instant a current finding shares it, regardless of
+        // where either one now sits in the file.
+        let fingerprint_idx: HashMap<&str, usize> = baseline
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| !b.fingerprint.is_empty())
+            .map(|(i, b)| (b.fingerprint.as_str(), i))
+            .collect();
+
+        let ordinals = crate::commands::scan::fingerprint_ordinals(&current);
+        let mut unmatched = Vec::new();
+        for (diag, ordinal) in current.into_iter().zip(ordinals) {
+            let fp = diag.fingerprint(ordinal);
+            match fingerprint_idx.get(fp.as_str()) {
+                Some(&i) if !consumed[i] => consumed[i] = true,
+                _ => unmatched.push(diag),
+            }
+        }
+
+        let mut baseline_by_key: HashMap<(&str, &str, i64, i64), Vec<usize>> = HashMap::new();
+        for (i, b) in baseline.iter().enumerate() {
+            if consumed[i] {
+                continue;
+            }
+            baseline_by_key
+                .entry((b.path.as_str(), b.rule_id.as_str(), b.line, b.col))
+                .or_default()
+                .push(i);
+        }
+
+        let mut still_unmatched = Vec::new();
+        for diag in unmatched {
+            let key = (
+                diag.path.as_str(),
+                diag.id.as_str(),
+                diag.line as i64,
+                diag.col as i64,
+            );
+            match baseline_by_key.get_mut(&key).and_then(|stack| stack.pop()) {
+                Some(i) => consumed[i] = true,
+                None => still_unmatched.push(diag),
+            }
+        }
+        let unmatched = still_unmatched;
+
+        let mut new = Vec::new();
+        for diag in unmatched {
+            let closest = baseline
+                .iter()
+                .enumerate()
+                .filter(|(i, b)| !consumed[*i] && b.path == diag.path && b.rule_id == diag.id)
+                .map(|(i, b)| (i, (b.line - diag.line as i64).abs()))
+                .filter(|(_, drift)| *drift <= BASELINE_LINE_DRIFT)
+                .min_by_key(|(_, drift)| *drift);
+
+            match closest {
+                Some((i, _)) => consumed[i] = true,
+                None => new.push(diag),
+            }
+        }
+
+        let fixed = baseline
+            .into_iter()
+            .zip(consumed)
+            .filter_map(|(b, was_matched)| (!was_matched).then_some(b))
+            .collect();
+
+        BaselineDiff { new, fixed }
+    }
+
     // TODO: ADD CLEANS FOR EACH TABLE BASED ON PROJECT WHICH RUNS ON CLEAN
     // TODO: ADD DROP AND GIVE A CLI PARAMETER FOR DROP
 
@@ -57,30 +221,210 @@ pub mod index {
         pub severity: &'a str,
         pub line: i64,
         pub col: i64,
+        /// Flattened `Cap` bits in play at this finding's sink, `0` for a
+        /// plain pattern match that never went through the taint engine.
+        pub caps: u64,
+        /// [`Diag::fingerprint`] — this finding's stable, line-drift-proof
+        /// identity, used by [`diff_against_baseline`] to recognise it
+        /// across reruns.
+        pub fingerprint: String,
+        /// `serde_json`-encoded `Vec<FlowSpan>`, `"[]"` for a finding with
+        /// no taint-flow chain.
+        pub spans_json: String,
     }
 
-    pub struct Indexer {
-        conn: PooledConnection<SqliteConnectionManager>,
-        project: String,
+    /// One function-level content hash, ready for insertion into
+    /// `file_functions`. Keyed the same way as `function_summaries.hash` so
+    /// an unchanged function's summary row is reused as-is on rescan.
+    #[derive(Debug, Clone)]
+    pub struct FileFunctionRow<'a> {
+        pub fn_hash: &'a str,
+        pub name: &'a str,
+        pub start_line: i64,
+        pub end_line: i64,
     }
 
-    impl Indexer {
-        pub fn init(database_path: &Path) -> NyxResult<Arc<Pool<SqliteConnectionManager>>> {
-            let flags = OpenFlags::SQLITE_OPEN_READ_WRITE
-                | OpenFlags::SQLITE_OPEN_CREATE
-                | OpenFlags::SQLITE_OPEN_FULL_MUTEX;
-            let manager = SqliteConnectionManager::file(database_path).with_flags(flags);
-            let pool = Arc::new(Pool::new(manager)?);
+    /// Criteria for [`IndexStore::query_issues`]. `min_severity` is a threshold:
+    /// only issues *at least as severe* as it are returned (`High` is the
+    /// most restrictive, `Low` the most permissive), matching `Severity`'s
+    /// declaration-order `Ord` impl.
+    #[derive(Debug, Clone, Default)]
+    pub struct IssueFilter {
+        pub min_severity: Severity,
+        /// Substring/glob match against `rule_id`, as a SQL `LIKE` pattern
+        /// (e.g. `%sql%`); pass through as-is so callers control wildcards.
+        pub rule_like: Option<String>,
+        pub path_prefix: Option<String>,
+        pub limit: Option<usize>,
+    }
 
-            {
-                let conn = pool.get()?;
-                conn.pragma_update(None, "journal_mode", "WAL")?;
-                conn.execute_batch(SCHEMA)?;
+    /// Storage backend for the project issue/summary index. `Indexer` is a
+    /// thin dispatcher over one of these — the SQLite-backed default, or the
+    /// in-memory store built by tests (no command-line option selects it) — so neither
+    /// call sites nor tests need to know which one they're talking to.
+    ///
+    /// Kept object-safe (no generic methods) so `Indexer` can hold a
+    /// `Box<dyn IndexStore>`; `Indexer` itself still exposes the old
+    /// `impl IntoIterator` ergonomics on top.
+    pub trait IndexStore: Send {
+        fn should_scan(&self, path: &Path) -> NyxResult<bool>;
+        fn upsert_file(&self, path: &Path) -> NyxResult<i64>;
+        fn replace_issues(&mut self, file_id: i64, issues: &[IssueRow<'_>]) -> NyxResult<()>;
+        fn get_issues_from_file(&self, path: &Path) -> NyxResult<Vec<Diag>>;
+        fn upsert_summary(
+            &mut self,
+            project: &str,
+            path: &Path,
+            hash: &str,
+            s: &FuncSummary,
+            embedder: &dyn Embedder,
+        ) -> NyxResult<()>;
+        fn load_all_summaries(&self, project: &str) -> NyxResult<Vec<FuncSummary>>;
+        /// Top-`k` nearest summaries in `project` by cosine similarity to
+        /// `query`, highest similarity first. Rows embedded with a
+        /// different `dim` (i.e. a different model) are skipped.
+        fn find_similar(
+            &self,
+            project: &str,
+            query: &[f32],
+            k: usize,
+        ) -> NyxResult<Vec<(FuncSummary, f32)>>;
+        fn get_files(&self, project: &str) -> NyxResult<Vec<PathBuf>>;
+        /// Drop every indexed file under `project` whose path isn't in
+        /// `keep` (and, via `ON DELETE CASCADE`, its issues/function
+        /// hashes with it). Used after an incremental walk to clean up
+        /// files that were deleted or moved since the last index build.
+        /// Returns the number of files removed.
+        fn remove_missing_files(&mut self, project: &str, keep: &HashSet<PathBuf>) -> NyxResult<usize>;
+        /// Replace `file_id`'s function-level content hashes with `funcs`,
+        /// used by [`crate::functions::diff_and_store_function_hashes`] to
+        /// figure out which functions actually changed since last scan.
+        fn replace_file_functions(
+            &mut self,
+            file_id: i64,
+            funcs: &[FileFunctionRow<'_>],
+        ) -> NyxResult<()>;
+        /// The set of function hashes stored for `file_id` as of the last scan.
+        fn get_file_function_hashes(&self, file_id: i64) -> NyxResult<HashSet<String>>;
+        fn query_issues(&self, filter: &IssueFilter) -> NyxResult<Vec<Diag>>;
+        /// Every distinct `rule_id` currently present in `project`'s issues,
+        /// sorted ascending — the alphabetical order an `fst::Set` requires
+        /// at build time. Used by `nyx query --fuzzy` to build the FST the
+        /// fuzzy/prefix lookup runs against, without scanning every issue row.
+        fn distinct_rule_ids(&self, project: &str) -> NyxResult<Vec<String>>;
+        /// Evaluate a parsed `nyx query` DSL expression ([`crate::utils::query_lang`])
+        /// against `project`'s issues, highest-severity-first ordering left to
+        /// the caller (unlike `query_issues`, there's no threshold to re-sort by).
+        fn query_expr(&self, project: &str, expr: &Expr) -> NyxResult<Vec<Diag>>;
+        /// Snapshot every currently-indexed issue as the named baseline,
+        /// replacing any prior snapshot under that name.
+        fn create_baseline(&mut self, name: &str) -> NyxResult<()>;
+        /// Diff the current issue set against a previously created baseline.
+        fn diff_baseline(&self, name: &str) -> NyxResult<BaselineDiff>;
+        fn clear(&self) -> NyxResult<()>;
+        fn vacuum(&self) -> NyxResult<()>;
+    }
+
+    fn digest_file(path: &Path) -> NyxResult<Vec<u8>> {
+        let mut hasher = blake3::Hasher::new();
+        let mut file = fs::File::open(path)?;
+        std::io::copy(&mut file, &mut hasher)?;
+        Ok(hasher.finalize().as_bytes().to_vec())
+    }
+
+    fn pack_f32_le(v: &[f32]) -> Vec<u8> {
+        let mut out = Vec::with_capacity(v.len() * 4);
+        for f in v {
+            out.extend_from_slice(&f.to_le_bytes());
+        }
+        out
+    }
+
+    /// Inverse of `serde_json::to_string` on a `Vec<FlowSpan>` — malformed
+    /// or pre-chunk7-4 rows (empty string) fall back to no spans rather
+    /// than failing the whole row.
+    fn decode_spans(json: &str) -> Vec<FlowSpan> {
+        serde_json::from_str(json).unwrap_or_default()
+    }
+
+    fn unpack_f32_le(bytes: &[u8]) -> Vec<f32> {
+        bytes
+            .chunks_exact(4)
+            .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+            .collect()
+    }
+
+    fn l2_norm(v: &[f32]) -> f32 {
+        v.iter().map(|x| x * x).sum::<f32>().sqrt()
+    }
+
+    /// Cosine similarity, taking the query's L2 norm precomputed so a
+    /// `find_similar` scan over many rows only computes it once.
+    fn cosine_similarity(query: &[f32], query_norm: f32, other: &[f32]) -> f32 {
+        if query_norm == 0.0 {
+            return 0.0;
+        }
+        let other_norm = l2_norm(other);
+        if other_norm == 0.0 {
+            return 0.0;
+        }
+        let dot: f32 = query.iter().zip(other.iter()).map(|(a, b)| a * b).sum();
+        dot / (query_norm * other_norm)
+    }
+
+    /// Wraps a similarity score with its summary so a bounded `BinaryHeap`
+    /// can order on score alone (`f32` has no `Ord`; NaN can't occur here
+    /// since `cosine_similarity` only ever divides by a verified-nonzero norm).
+    struct Scored(f32, FuncSummary);
+
+    impl PartialEq for Scored {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Scored {}
+    impl PartialOrd for Scored {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Scored {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.total_cmp(&other.0)
+        }
+    }
+
+    /// Keep the top-`k` `(FuncSummary, similarity)` pairs from `rows` using a
+    /// bounded min-heap, rather than sorting the whole candidate set.
+    fn top_k_by_similarity(
+        rows: impl Iterator<Item = (FuncSummary, f32)>,
+        k: usize,
+    ) -> Vec<(FuncSummary, f32)> {
+        let mut heap: BinaryHeap<Reverse<Scored>> = BinaryHeap::with_capacity(k + 1);
+        for (summary, sim) in rows {
+            heap.push(Reverse(Scored(sim, summary)));
+            if heap.len() > k {
+                heap.pop();
             }
-            Ok(pool)
         }
 
-        pub fn from_pool(project: &str, pool: &Pool<SqliteConnectionManager>) -> NyxResult<Self> {
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(Scored(sim, s))| (s, sim))
+            .collect()
+    }
+
+    // -------------------------------------------------------------------------
+    // SQLite-backed store (the default)
+    // -------------------------------------------------------------------------
+
+    pub struct SqliteStore {
+        conn: PooledConnection<SqliteConnectionManager>,
+        project: String,
+    }
+
+    impl SqliteStore {
+        fn new(project: &str, pool: &Pool<SqliteConnectionManager>) -> NyxResult<Self> {
             let conn = pool.get()?;
             Ok(Self {
                 conn,
@@ -92,12 +436,13 @@ pub mod index {
         fn c(&self) -> &Connection {
             self.conn.deref()
         }
+    }
 
-        /// Return true when the file *content* or *mtime* changed since the last scan.
-        pub fn should_scan(&self, path: &Path) -> NyxResult<bool> {
+    impl IndexStore for SqliteStore {
+        fn should_scan(&self, path: &Path) -> NyxResult<bool> {
             let meta = fs::metadata(path)?;
             let mtime = meta.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as i64;
-            let digest = Self::digest_file(path)?;
+            let digest = digest_file(path)?;
 
             let row: Option<(Vec<u8>, i64)> = self
                 .conn
@@ -114,12 +459,11 @@ pub mod index {
             })
         }
 
-        /// Insert or update the `files` row and return its id.
-        pub fn upsert_file(&self, path: &Path) -> NyxResult<i64> {
+        fn upsert_file(&self, path: &Path) -> NyxResult<i64> {
             let meta = fs::metadata(path)?;
             let mtime = meta.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as i64;
             let scanned_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
-            let digest = Self::digest_file(path)?;
+            let digest = digest_file(path)?;
 
             self.c().execute(
                 "INSERT INTO files (project, path, hash, mtime, scanned_at)
@@ -145,19 +489,14 @@ pub mod index {
             Ok(id)
         }
 
-        /// Replace all issues for `file_id` with the supplied set.
-        pub fn replace_issues<'a>(
-            &mut self,
-            file_id: i64,
-            issues: impl IntoIterator<Item = IssueRow<'a>>,
-        ) -> NyxResult<()> {
+        fn replace_issues(&mut self, file_id: i64, issues: &[IssueRow<'_>]) -> NyxResult<()> {
             let tx = self.conn.transaction()?;
             tx.execute("DELETE FROM issues WHERE file_id = ?", params![file_id])?;
 
             {
                 let mut stmt = tx.prepare(
-                    "INSERT INTO issues (file_id, rule_id, severity, line, col)
-                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    "INSERT INTO issues (file_id, rule_id, severity, line, col, caps, fingerprint, spans_json)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
                 )?;
                 for iss in issues {
                     stmt.execute(params![
@@ -165,7 +504,10 @@ pub mod index {
                         iss.rule_id,
                         iss.severity,
                         iss.line,
-                        iss.col
+                        iss.col,
+                        iss.caps as i64,
+                        iss.fingerprint,
+                        iss.spans_json,
                     ])?;
                 }
             }
@@ -173,8 +515,45 @@ pub mod index {
             Ok(())
         }
 
-        /// Gets the issues for a specific file so we don't have to rescan
-        pub fn get_issues_from_file(&self, path: &Path) -> NyxResult<Vec<Diag>> {
+        fn replace_file_functions(
+            &mut self,
+            file_id: i64,
+            funcs: &[FileFunctionRow<'_>],
+        ) -> NyxResult<()> {
+            let tx = self.conn.transaction()?;
+            tx.execute(
+                "DELETE FROM file_functions WHERE file_id = ?",
+                params![file_id],
+            )?;
+
+            {
+                let mut stmt = tx.prepare(
+                    "INSERT INTO file_functions (file_id, fn_hash, name, start_line, end_line)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                )?;
+                for f in funcs {
+                    stmt.execute(params![
+                        file_id,
+                        f.fn_hash,
+                        f.name,
+                        f.start_line,
+                        f.end_line
+                    ])?;
+                }
+            }
+            tx.commit()?;
+            Ok(())
+        }
+
+        fn get_file_function_hashes(&self, file_id: i64) -> NyxResult<HashSet<String>> {
+            let mut stmt = self
+                .c()
+                .prepare("SELECT fn_hash FROM file_functions WHERE file_id = ?1")?;
+            let rows = stmt.query_map(params![file_id], |r| r.get::<_, String>(0))?;
+            Ok(rows.filter_map(Result::ok).collect())
+        }
+
+        fn get_issues_from_file(&self, path: &Path) -> NyxResult<Vec<Diag>> {
             let file_id: i64 = self.c().query_row(
                 "SELECT id FROM files WHERE project = ?1 AND path = ?2",
                 params![self.project, path.to_string_lossy()],
@@ -182,39 +561,51 @@ pub mod index {
             )?;
 
             let mut stmt = self.c().prepare(
-                "SELECT rule_id, severity, line, col
+                "SELECT rule_id, severity, line, col, caps, spans_json
          FROM issues
          WHERE file_id = ?1",
             )?;
 
             let issue_iter = stmt.query_map([file_id], |row| {
                 let sev_str: String = row.get(1)?;
+                let caps: i64 = row.get(4)?;
+                let spans_json: String = row.get(5)?;
                 Ok(Diag {
                     path: path.to_string_lossy().to_string(),
                     id: row.get::<_, String>(0)?, // rule_id
                     line: row.get::<_, i64>(2)? as usize,
                     col: row.get::<_, i64>(3)? as usize,
                     severity: Severity::from_str(&sev_str).unwrap(),
+                    end_line: None,
+                    end_col: None,
+                    title: None,
+                    spans: decode_spans(&spans_json),
+                    caps: Cap::from_bits_truncate(caps as u64),
                 })
             })?;
 
             Ok(issue_iter.filter_map(Result::ok).collect())
         }
 
-        pub fn upsert_summary(
+        fn upsert_summary(
             &mut self,
             project: &str,
             path: &Path,
             hash: &str,
-            s: &crate::summary::FuncSummary,
+            s: &FuncSummary,
+            embedder: &dyn Embedder,
         ) -> NyxResult<()> {
+            let embedding = embedder.embed(&s.name);
+            let packed = pack_f32_le(&embedding);
+            let now = chrono::Utc::now().timestamp_millis(); // i64
             let conn = self.c();
-            let now  = chrono::Utc::now().timestamp_millis(); // i64
 
             conn.execute(
-                "INSERT INTO function_summaries (hash, project, name, lang, summary, updated_at)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                "INSERT INTO function_summaries (hash, project, name, lang, summary, embedding, dim, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
                      ON CONFLICT(hash) DO UPDATE SET summary = excluded.summary,
+                                                     embedding = excluded.embedding,
+                                                     dim = excluded.dim,
                                                      updated_at = excluded.updated_at",
                 (
                     hash,
@@ -222,31 +613,243 @@ pub mod index {
                     &s.name,
                     path.extension().and_then(|e| e.to_str()).unwrap_or_default(),
                     serde_json::to_string(s).unwrap(), //TODO REPLACE UNWRAP
+                    packed,
+                    embedding.len() as i64,
                     now,
                 ),
             )?;
             Ok(())
         }
 
-        pub fn load_all_summaries(&self, project: &str) -> NyxResult<Vec<crate::summary::FuncSummary<'static>>> {
+        fn load_all_summaries(&self, project: &str) -> NyxResult<Vec<FuncSummary>> {
             let mut stmt = self
                 .c()
                 .prepare("SELECT summary FROM function_summaries WHERE project = ?1")?;
 
             let iter = stmt.query_map([project], |row| {
                 let json: String = row.get(0)?;
-                Ok(serde_json::from_str::<crate::summary::FuncSummary>(json.as_str()).unwrap()) // TODO: REPLACE UNWRAP
+                Ok(serde_json::from_str::<FuncSummary>(json.as_str()).unwrap()) // TODO: REPLACE UNWRAP
             })?;
-            
-            Ok(iter
-                .collect::<Result<Vec<_>, _>>()?
-                .into_iter()
-                .map(|s| unsafe { std::mem::transmute::<_, crate::summary::FuncSummary<'static>>(s) })
-                .collect())
+
+            Ok(iter.collect::<Result<Vec<_>, _>>()?)
         }
 
-        /// gets files from the database
-        pub fn get_files(&self, project: &str) -> NyxResult<Vec<PathBuf>> {
+        fn find_similar(
+            &self,
+            project: &str,
+            query: &[f32],
+            k: usize,
+        ) -> NyxResult<Vec<(FuncSummary, f32)>> {
+            let mut stmt = self
+                .c()
+                .prepare("SELECT summary, embedding, dim FROM function_summaries WHERE project = ?1")?;
+
+            let rows = stmt.query_map(params![project], |row| {
+                let json: String = row.get(0)?;
+                let embedding: Vec<u8> = row.get(1)?;
+                let dim: i64 = row.get(2)?;
+                Ok((json, embedding, dim as usize))
+            })?;
+
+            let query_norm = l2_norm(query);
+            let candidates = rows.filter_map(Result::ok).filter_map(|(json, bytes, dim)| {
+                if dim != query.len() {
+                    return None;
+                }
+                let summary = serde_json::from_str::<FuncSummary>(&json).ok()?;
+                let sim = cosine_similarity(query, query_norm, &unpack_f32_le(&bytes));
+                Some((summary, sim))
+            });
+
+            Ok(top_k_by_similarity(candidates, k))
+        }
+
+        fn query_issues(&self, filter: &IssueFilter) -> NyxResult<Vec<Diag>> {
+            let mut sql = String::from(
+                "SELECT files.path, issues.rule_id, issues.severity, issues.line, issues.col,
+                        issues.caps, issues.spans_json
+                 FROM issues JOIN files ON files.id = issues.file_id
+                 WHERE files.project = ?1",
+            );
+
+            let mut args: Vec<Box<dyn rusqlite::types::ToSql>> =
+                vec![Box::new(self.project.clone())];
+
+            if let Some(prefix) = &filter.path_prefix {
+                sql.push_str(&format!(" AND files.path LIKE ?{}", args.len() + 1));
+                args.push(Box::new(format!("{prefix}%")));
+            }
+
+            if let Some(needle) = &filter.rule_like {
+                sql.push_str(&format!(" AND issues.rule_id LIKE ?{}", args.len() + 1));
+                args.push(Box::new(needle.clone()));
+            }
+
+            sql.push_str(" ORDER BY files.path, issues.line, issues.col");
+
+            let mut stmt = self.c().prepare(&sql)?;
+            let params: Vec<&dyn rusqlite::types::ToSql> =
+                args.iter().map(|b| b.as_ref()).collect();
+
+            let rows = stmt.query_map(params.as_slice(), |row| {
+                let sev_str: String = row.get(2)?;
+                let caps: i64 = row.get(5)?;
+                let spans_json: String = row.get(6)?;
+                Ok(Diag {
+                    path: row.get::<_, String>(0)?,
+                    id: row.get::<_, String>(1)?,
+                    severity: Severity::from_str(&sev_str).unwrap(),
+                    line: row.get::<_, i64>(3)? as usize,
+                    col: row.get::<_, i64>(4)? as usize,
+                    end_line: None,
+                    end_col: None,
+                    title: None,
+                    spans: decode_spans(&spans_json),
+                    caps: Cap::from_bits_truncate(caps as u64),
+                })
+            })?;
+
+            let mut diags: Vec<Diag> = rows
+                .filter_map(Result::ok)
+                .filter(|d| d.severity <= filter.min_severity)
+                .collect();
+
+            if let Some(limit) = filter.limit {
+                diags.truncate(limit);
+            }
+
+            Ok(diags)
+        }
+
+        fn distinct_rule_ids(&self, project: &str) -> NyxResult<Vec<String>> {
+            let mut stmt = self.c().prepare(
+                "SELECT DISTINCT issues.rule_id
+                 FROM issues JOIN files ON files.id = issues.file_id
+                 WHERE files.project = ?1
+                 ORDER BY issues.rule_id",
+            )?;
+            let rows = stmt.query_map(params![project], |row| row.get::<_, String>(0))?;
+            Ok(rows.filter_map(Result::ok).collect())
+        }
+
+        fn query_expr(&self, project: &str, expr: &Expr) -> NyxResult<Vec<Diag>> {
+            let (where_sql, extra) = query_lang::compile(expr)?;
+            let sql = format!(
+                "SELECT files.path, issues.rule_id, issues.severity, issues.line, issues.col,
+                        issues.caps, issues.spans_json
+                 FROM issues JOIN files ON files.id = issues.file_id
+                 WHERE files.project = ? AND ({where_sql})
+                 ORDER BY files.path, issues.line, issues.col"
+            );
+
+            let mut args: Vec<Box<dyn rusqlite::types::ToSql>> = vec![Box::new(project.to_owned())];
+            for v in extra {
+                let query_lang::SqlValue::Text(s) = v;
+                args.push(Box::new(s));
+            }
+
+            let mut stmt = self.c().prepare(&sql)?;
+            let params: Vec<&dyn rusqlite::types::ToSql> =
+                args.iter().map(|b| b.as_ref()).collect();
+
+            let rows = stmt.query_map(params.as_slice(), |row| {
+                let sev_str: String = row.get(2)?;
+                let caps: i64 = row.get(5)?;
+                let spans_json: String = row.get(6)?;
+                Ok(Diag {
+                    path: row.get::<_, String>(0)?,
+                    id: row.get::<_, String>(1)?,
+                    severity: Severity::from_str(&sev_str).unwrap(),
+                    line: row.get::<_, i64>(3)? as usize,
+                    col: row.get::<_, i64>(4)? as usize,
+                    end_line: None,
+                    end_col: None,
+                    title: None,
+                    spans: decode_spans(&spans_json),
+                    caps: Cap::from_bits_truncate(caps as u64),
+                })
+            })?;
+
+            Ok(rows.filter_map(Result::ok).collect())
+        }
+
+        fn create_baseline(&mut self, name: &str) -> NyxResult<()> {
+            let current = self.query_issues(&IssueFilter::default())?;
+            let now = chrono::Utc::now().timestamp_millis();
+            let project = self.project.clone();
+
+            let tx = self.conn.transaction()?;
+            tx.execute(
+                "INSERT INTO baselines (project, name, created_at) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(project, name) DO UPDATE SET created_at = excluded.created_at",
+                params![project, name, now],
+            )?;
+            let baseline_id: i64 = tx.query_row(
+                "SELECT id FROM baselines WHERE project = ?1 AND name = ?2",
+                params![project, name],
+                |r| r.get(0),
+            )?;
+
+            tx.execute(
+                "DELETE FROM baseline_issues WHERE baseline_id = ?1",
+                params![baseline_id],
+            )?;
+            {
+                let ordinals = crate::commands::scan::fingerprint_ordinals(&current);
+                let mut stmt = tx.prepare(
+                    "INSERT INTO baseline_issues (baseline_id, path, rule_id, line, col, fingerprint)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )?;
+                for (d, &ordinal) in current.iter().zip(&ordinals) {
+                    stmt.execute(params![
+                        baseline_id,
+                        d.path,
+                        d.id,
+                        d.line as i64,
+                        d.col as i64,
+                        d.fingerprint(ordinal),
+                    ])?;
+                }
+            }
+            tx.commit()?;
+            Ok(())
+        }
+
+        fn diff_baseline(&self, name: &str) -> NyxResult<BaselineDiff> {
+            let baseline_id: Option<i64> = self
+                .c()
+                .query_row(
+                    "SELECT id FROM baselines WHERE project = ?1 AND name = ?2",
+                    params![self.project, name],
+                    |r| r.get(0),
+                )
+                .optional()?;
+
+            let Some(baseline_id) = baseline_id else {
+                return Err(NyxError::from(format!(
+                    "no baseline named '{name}' for this project"
+                )));
+            };
+
+            let mut stmt = self.c().prepare(
+                "SELECT path, rule_id, line, col, fingerprint FROM baseline_issues WHERE baseline_id = ?1",
+            )?;
+            let rows = stmt.query_map(params![baseline_id], |row| {
+                Ok(BaselineFinding {
+                    path: row.get(0)?,
+                    rule_id: row.get(1)?,
+                    line: row.get(2)?,
+                    col: row.get(3)?,
+                    fingerprint: row.get(4)?,
+                })
+            })?;
+            let baseline: Vec<BaselineFinding> = rows.filter_map(Result::ok).collect();
+
+            let current = self.query_issues(&IssueFilter::default())?;
+            Ok(diff_against_baseline(current, baseline))
+        }
+
+        fn get_files(&self, project: &str) -> NyxResult<Vec<PathBuf>> {
             let mut stmt = self.c().prepare(
                 "SELECT path
          FROM files
@@ -260,10 +863,25 @@ pub mod index {
                 .collect::<Result<_, _>>()?)
         }
 
-        // -------------------------------------------------------------------------
-        // Maintenance utilities
-        // -------------------------------------------------------------------------
-        pub fn clear(&self) -> NyxResult<()> {
+        fn remove_missing_files(&mut self, project: &str, keep: &HashSet<PathBuf>) -> NyxResult<usize> {
+            let existing = self.get_files(project)?;
+            let stale: Vec<&PathBuf> = existing.iter().filter(|p| !keep.contains(*p)).collect();
+            if stale.is_empty() {
+                return Ok(0);
+            }
+
+            let tx = self.conn.transaction()?;
+            for path in &stale {
+                tx.execute(
+                    "DELETE FROM files WHERE project = ?1 AND path = ?2",
+                    params![project, path.to_string_lossy()],
+                )?;
+            }
+            tx.commit()?;
+            Ok(stale.len())
+        }
+
+        fn clear(&self) -> NyxResult<()> {
             self.c().execute_batch(
                 r#"
         PRAGMA foreign_keys = OFF;
@@ -281,19 +899,551 @@ pub mod index {
             Ok(())
         }
 
-        pub fn vacuum(&self) -> NyxResult<()> {
+        fn vacuum(&self) -> NyxResult<()> {
             self.c().execute("VACUUM;", [])?;
             Ok(())
         }
+    }
+
+    // -------------------------------------------------------------------------
+    // In-memory store — used by tests, no command-line option selects it,
+    // never touches disk.
+    // -------------------------------------------------------------------------
+
+    #[derive(Debug, Clone)]
+    struct MemoryFile {
+        id: i64,
+        hash: Vec<u8>,
+        mtime: i64,
+    }
+
+    #[derive(Debug, Clone)]
+    struct OwnedIssue {
+        rule_id: String,
+        severity: String,
+        line: i64,
+        col: i64,
+        caps: u64,
+        spans: Vec<FlowSpan>,
+    }
+
+    #[derive(Debug, Default)]
+    struct MemoryState {
+        files: HashMap<PathBuf, MemoryFile>,
+        issues: HashMap<i64, Vec<OwnedIssue>>,
+        // hash -> (project, summary, embedding)
+        summaries: HashMap<String, (String, FuncSummary, Vec<f32>)>,
+        baselines: HashMap<String, Vec<BaselineFinding>>,
+        // file_id -> (fn_hash, name, start_line, end_line)
+        file_functions: HashMap<i64, Vec<(String, String, i64, i64)>>,
+    }
+
+    pub struct MemoryStore {
+        project: String,
+        state: Mutex<MemoryState>,
+        next_id: AtomicI64,
+    }
+
+    impl MemoryStore {
+        pub fn new(project: &str) -> Self {
+            Self {
+                project: project.to_owned(),
+                state: Mutex::new(MemoryState::default()),
+                next_id: AtomicI64::new(1),
+            }
+        }
+    }
+
+    /// Minimal SQL-`LIKE` match supporting only leading/trailing `%`
+    /// wildcards, which is all `IssueFilter`'s callers ever produce.
+    fn like_match(value: &str, pattern: &str) -> bool {
+        let prefix = pattern.starts_with('%');
+        let suffix = pattern.ends_with('%');
+        let needle = pattern.trim_matches('%');
+
+        match (prefix, suffix) {
+            (true, true) => value.contains(needle),
+            (true, false) => value.ends_with(needle),
+            (false, true) => value.starts_with(needle),
+            (false, false) => value == needle,
+        }
+    }
+
+    impl IndexStore for MemoryStore {
+        fn should_scan(&self, path: &Path) -> NyxResult<bool> {
+            let meta = fs::metadata(path)?;
+            let mtime = meta.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as i64;
+            let digest = digest_file(path)?;
+
+            let state = self.state.lock()?;
+            Ok(match state.files.get(path) {
+                Some(f) => f.hash != digest || f.mtime != mtime,
+                None => true,
+            })
+        }
+
+        fn upsert_file(&self, path: &Path) -> NyxResult<i64> {
+            let meta = fs::metadata(path)?;
+            let mtime = meta.modified()?.duration_since(UNIX_EPOCH)?.as_secs() as i64;
+            let digest = digest_file(path)?;
+
+            let mut state = self.state.lock()?;
+            let id = state
+                .files
+                .get(path)
+                .map(|f| f.id)
+                .unwrap_or_else(|| self.next_id.fetch_add(1, Ordering::Relaxed));
+
+            state.files.insert(
+                path.to_path_buf(),
+                MemoryFile {
+                    id,
+                    hash: digest,
+                    mtime,
+                },
+            );
+            Ok(id)
+        }
+
+        fn replace_issues(&mut self, file_id: i64, issues: &[IssueRow<'_>]) -> NyxResult<()> {
+            let owned = issues
+                .iter()
+                .map(|i| OwnedIssue {
+                    rule_id: i.rule_id.to_owned(),
+                    severity: i.severity.to_owned(),
+                    line: i.line,
+                    col: i.col,
+                    caps: i.caps,
+                    spans: decode_spans(&i.spans_json),
+                })
+                .collect();
+            self.state.lock()?.issues.insert(file_id, owned);
+            Ok(())
+        }
+
+        fn replace_file_functions(
+            &mut self,
+            file_id: i64,
+            funcs: &[FileFunctionRow<'_>],
+        ) -> NyxResult<()> {
+            let owned = funcs
+                .iter()
+                .map(|f| (f.fn_hash.to_owned(), f.name.to_owned(), f.start_line, f.end_line))
+                .collect();
+            self.state.lock()?.file_functions.insert(file_id, owned);
+            Ok(())
+        }
+
+        fn get_file_function_hashes(&self, file_id: i64) -> NyxResult<HashSet<String>> {
+            Ok(self
+                .state
+                .lock()?
+                .file_functions
+                .get(&file_id)
+                .into_iter()
+                .flatten()
+                .map(|(h, ..)| h.clone())
+                .collect())
+        }
+
+        fn get_issues_from_file(&self, path: &Path) -> NyxResult<Vec<Diag>> {
+            let state = self.state.lock()?;
+            let Some(file) = state.files.get(path) else {
+                return Ok(Vec::new());
+            };
+
+            Ok(state
+                .issues
+                .get(&file.id)
+                .into_iter()
+                .flatten()
+                .map(|i| Diag {
+                    path: path.to_string_lossy().into_owned(),
+                    id: i.rule_id.clone(),
+                    severity: Severity::from_str(&i.severity).unwrap(),
+                    line: i.line as usize,
+                    col: i.col as usize,
+                    end_line: None,
+                    end_col: None,
+                    title: None,
+                    spans: i.spans.clone(),
+                    caps: Cap::from_bits_truncate(i.caps),
+                })
+                .collect())
+        }
+
+        fn upsert_summary(
+            &mut self,
+            project: &str,
+            _path: &Path,
+            hash: &str,
+            s: &FuncSummary,
+            embedder: &dyn Embedder,
+        ) -> NyxResult<()> {
+            let embedding = embedder.embed(&s.name);
+            self.state
+                .lock()?
+                .summaries
+                .insert(hash.to_owned(), (project.to_owned(), s.clone(), embedding));
+            Ok(())
+        }
+
+        fn load_all_summaries(&self, project: &str) -> NyxResult<Vec<FuncSummary>> {
+            Ok(self
+                .state
+                .lock()?
+                .summaries
+                .values()
+                .filter(|(p, ..)| p == project)
+                .map(|(_, s, _)| s.clone())
+                .collect())
+        }
+
+        fn find_similar(
+            &self,
+            project: &str,
+            query: &[f32],
+            k: usize,
+        ) -> NyxResult<Vec<(FuncSummary, f32)>> {
+            let state = self.state.lock()?;
+            let query_norm = l2_norm(query);
+
+            let candidates = state
+                .summaries
+                .values()
+                .filter(|(p, ..)| p == project)
+                .filter(|(_, _, emb)| emb.len() == query.len())
+                .map(|(_, s, emb)| (s.clone(), cosine_similarity(query, query_norm, emb)));
+
+            Ok(top_k_by_similarity(candidates, k))
+        }
+
+        fn get_files(&self, project: &str) -> NyxResult<Vec<PathBuf>> {
+            if project != self.project {
+                return Ok(Vec::new());
+            }
+            Ok(self.state.lock()?.files.keys().cloned().collect())
+        }
+
+        fn remove_missing_files(&mut self, project: &str, keep: &HashSet<PathBuf>) -> NyxResult<usize> {
+            if project != self.project {
+                return Ok(0);
+            }
+
+            let mut state = self.state.lock()?;
+            let stale: Vec<PathBuf> = state
+                .files
+                .keys()
+                .filter(|p| !keep.contains(*p))
+                .cloned()
+                .collect();
+
+            for path in &stale {
+                if let Some(f) = state.files.remove(path) {
+                    state.issues.remove(&f.id);
+                    state.file_functions.remove(&f.id);
+                }
+            }
+            Ok(stale.len())
+        }
+
+        fn query_issues(&self, filter: &IssueFilter) -> NyxResult<Vec<Diag>> {
+            let state = self.state.lock()?;
+            let mut diags: Vec<Diag> = state
+                .files
+                .iter()
+                .flat_map(|(path, file)| {
+                    state
+                        .issues
+                        .get(&file.id)
+                        .into_iter()
+                        .flatten()
+                        .map(move |i| (path, i))
+                })
+                .filter(|(path, _)| match &filter.path_prefix {
+                    Some(p) => path.to_string_lossy().starts_with(p.as_str()),
+                    None => true,
+                })
+                .filter(|(_, i)| match &filter.rule_like {
+                    Some(pat) => like_match(&i.rule_id, pat),
+                    None => true,
+                })
+                .map(|(path, i)| Diag {
+                    path: path.to_string_lossy().into_owned(),
+                    id: i.rule_id.clone(),
+                    severity: Severity::from_str(&i.severity).unwrap(),
+                    line: i.line as usize,
+                    col: i.col as usize,
+                    end_line: None,
+                    end_col: None,
+                    title: None,
+                    spans: i.spans.clone(),
+                    caps: Cap::from_bits_truncate(i.caps),
+                })
+                .filter(|d| d.severity <= filter.min_severity)
+                .collect();
+
+            diags.sort_by(|a, b| (&a.path, a.line, a.col).cmp(&(&b.path, b.line, b.col)));
+
+            if let Some(limit) = filter.limit {
+                diags.truncate(limit);
+            }
+
+            Ok(diags)
+        }
+
+        fn distinct_rule_ids(&self, project: &str) -> NyxResult<Vec<String>> {
+            if project != self.project {
+                return Ok(Vec::new());
+            }
+
+            let state = self.state.lock()?;
+            let mut ids: Vec<String> = state
+                .issues
+                .values()
+                .flatten()
+                .map(|i| i.rule_id.clone())
+                .collect();
+            ids.sort_unstable();
+            ids.dedup();
+            Ok(ids)
+        }
+
+        fn query_expr(&self, project: &str, expr: &Expr) -> NyxResult<Vec<Diag>> {
+            if project != self.project {
+                return Ok(Vec::new());
+            }
+
+            let state = self.state.lock()?;
+            let mut diags = Vec::new();
+            for (path, file) in &state.files {
+                for issue in state.issues.get(&file.id).into_iter().flatten() {
+                    let path_str = path.to_string_lossy();
+                    let record = IssueRecord {
+                        path: &path_str,
+                        rule_id: &issue.rule_id,
+                        severity: Severity::from_str(&issue.severity).unwrap(),
+                    };
+                    if query_lang::eval(expr, &record)? {
+                        diags.push(Diag {
+                            path: path_str.into_owned(),
+                            id: issue.rule_id.clone(),
+                            severity: record.severity,
+                            line: issue.line as usize,
+                            col: issue.col as usize,
+                            end_line: None,
+                            end_col: None,
+                            title: None,
+                            spans: issue.spans.clone(),
+                            caps: Cap::from_bits_truncate(issue.caps),
+                        });
+                    }
+                }
+            }
+
+            diags.sort_by(|a, b| (&a.path, a.line, a.col).cmp(&(&b.path, b.line, b.col)));
+            Ok(diags)
+        }
+
+        fn create_baseline(&mut self, name: &str) -> NyxResult<()> {
+            let current = self.query_issues(&IssueFilter::default())?;
+            let ordinals = crate::commands::scan::fingerprint_ordinals(&current);
+            let snapshot = current
+                .iter()
+                .zip(&ordinals)
+                .map(|(d, &ordinal)| BaselineFinding {
+                    path: d.path.clone(),
+                    rule_id: d.id.clone(),
+                    line: d.line as i64,
+                    col: d.col as i64,
+                    fingerprint: d.fingerprint(ordinal),
+                })
+                .collect();
+            self.state.lock()?.baselines.insert(name.to_owned(), snapshot);
+            Ok(())
+        }
+
+        fn diff_baseline(&self, name: &str) -> NyxResult<BaselineDiff> {
+            let baseline = {
+                let state = self.state.lock()?;
+                state.baselines.get(name).cloned().ok_or_else(|| {
+                    NyxError::from(format!("no baseline named '{name}' for this project"))
+                })?
+            };
+            let current = self.query_issues(&IssueFilter::default())?;
+            Ok(diff_against_baseline(current, baseline))
+        }
+
+        fn clear(&self) -> NyxResult<()> {
+            *self.state.lock()? = MemoryState::default();
+            Ok(())
+        }
+
+        fn vacuum(&self) -> NyxResult<()> {
+            Ok(()) // nothing to compact in-memory
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Indexer — thin dispatcher over a boxed IndexStore
+    // -------------------------------------------------------------------------
+
+    pub struct Indexer {
+        store: Box<dyn IndexStore>,
+    }
+
+    impl Indexer {
+        pub fn init(database_path: &Path) -> NyxResult<Arc<Pool<SqliteConnectionManager>>> {
+            let flags = OpenFlags::SQLITE_OPEN_READ_WRITE
+                | OpenFlags::SQLITE_OPEN_CREATE
+                | OpenFlags::SQLITE_OPEN_FULL_MUTEX;
+            let manager = SqliteConnectionManager::file(database_path).with_flags(flags);
+            let pool = Arc::new(Pool::new(manager)?);
+
+            {
+                let conn = pool.get()?;
+                conn.pragma_update(None, "journal_mode", "WAL")?;
+                conn.execute_batch(SCHEMA)?;
+            }
+            Ok(pool)
+        }
+
+        pub fn from_pool(project: &str, pool: &Pool<SqliteConnectionManager>) -> NyxResult<Self> {
+            Ok(Self {
+                store: Box::new(SqliteStore::new(project, pool)?),
+            })
+        }
+
+        /// In-memory store for tests (no command-line option selects it) — never touches disk.
+        pub fn in_memory(project: &str) -> Self {
+            Self {
+                store: Box::new(MemoryStore::new(project)),
+            }
+        }
+
+        /// Return true when the file *content* or *mtime* changed since the last scan.
+        pub fn should_scan(&self, path: &Path) -> NyxResult<bool> {
+            self.store.should_scan(path)
+        }
+
+        /// Insert or update the `files` row and return its id.
+        pub fn upsert_file(&self, path: &Path) -> NyxResult<i64> {
+            self.store.upsert_file(path)
+        }
+
+        /// Replace all issues for `file_id` with the supplied set.
+        pub fn replace_issues<'a>(
+            &mut self,
+            file_id: i64,
+            issues: impl IntoIterator<Item = IssueRow<'a>>,
+        ) -> NyxResult<()> {
+            let rows: Vec<IssueRow<'a>> = issues.into_iter().collect();
+            self.store.replace_issues(file_id, &rows)
+        }
+
+        /// Gets the issues for a specific file so we don't have to rescan
+        pub fn get_issues_from_file(&self, path: &Path) -> NyxResult<Vec<Diag>> {
+            self.store.get_issues_from_file(path)
+        }
+
+        /// Replace `file_id`'s stored function hashes with `funcs`.
+        pub fn replace_file_functions<'a>(
+            &mut self,
+            file_id: i64,
+            funcs: impl IntoIterator<Item = FileFunctionRow<'a>>,
+        ) -> NyxResult<()> {
+            let rows: Vec<FileFunctionRow<'a>> = funcs.into_iter().collect();
+            self.store.replace_file_functions(file_id, &rows)
+        }
+
+        /// The function hashes stored for `file_id` as of the last scan.
+        pub fn get_file_function_hashes(&self, file_id: i64) -> NyxResult<HashSet<String>> {
+            self.store.get_file_function_hashes(file_id)
+        }
+
+        pub fn upsert_summary(
+            &mut self,
+            project: &str,
+            path: &Path,
+            hash: &str,
+            s: &FuncSummary,
+            embedder: &dyn Embedder,
+        ) -> NyxResult<()> {
+            self.store.upsert_summary(project, path, hash, s, embedder)
+        }
+
+        pub fn load_all_summaries(&self, project: &str) -> NyxResult<Vec<FuncSummary>> {
+            self.store.load_all_summaries(project)
+        }
+
+        /// Top-`k` summaries in `project` most similar to `query` by cosine
+        /// similarity, highest similarity first.
+        pub fn find_similar(
+            &self,
+            project: &str,
+            query: &[f32],
+            k: usize,
+        ) -> NyxResult<Vec<(FuncSummary, f32)>> {
+            self.store.find_similar(project, query, k)
+        }
+
+        /// Read-only search over the issues already persisted for this project,
+        /// used by `nyx query` to triage historical scan results without
+        /// reparsing any files.
+        pub fn query_issues(&self, filter: &IssueFilter) -> NyxResult<Vec<Diag>> {
+            self.store.query_issues(filter)
+        }
+
+        /// Every distinct `rule_id` indexed for this project, sorted
+        /// ascending — the raw material `nyx query --fuzzy` feeds into an
+        /// `fst::Set` for prefix/Levenshtein lookup.
+        pub fn distinct_rule_ids(&self, project: &str) -> NyxResult<Vec<String>> {
+            self.store.distinct_rule_ids(project)
+        }
+
+        /// Evaluate a `nyx query` DSL expression (see [`crate::utils::query_lang`])
+        /// against this project's issues — the SQLite store compiles it to a
+        /// `WHERE` clause, the in-memory store interprets it directly.
+        pub fn query_expr(&self, project: &str, expr: &Expr) -> NyxResult<Vec<Diag>> {
+            self.store.query_expr(project, expr)
+        }
+
+        /// Snapshot the current issue set as the named baseline.
+        pub fn create_baseline(&mut self, name: &str) -> NyxResult<()> {
+            self.store.create_baseline(name)
+        }
+
+        /// Diff the current issue set against a previously created baseline,
+        /// for CI gates that should only fail on newly introduced issues.
+        pub fn diff_baseline(&self, name: &str) -> NyxResult<BaselineDiff> {
+            self.store.diff_baseline(name)
+        }
+
+        /// gets files from the database
+        pub fn get_files(&self, project: &str) -> NyxResult<Vec<PathBuf>> {
+            self.store.get_files(project)
+        }
+
+        /// Drop indexed files under `project` that aren't in `keep`, so an
+        /// incremental re-index doesn't leave stale rows for deleted files.
+        pub fn remove_missing_files<'a>(
+            &mut self,
+            project: &str,
+            keep: impl IntoIterator<Item = &'a Path>,
+        ) -> NyxResult<usize> {
+            let keep: HashSet<PathBuf> = keep.into_iter().map(Path::to_path_buf).collect();
+            self.store.remove_missing_files(project, &keep)
+        }
 
         // -------------------------------------------------------------------------
-        // Helpers
+        // Maintenance utilities
         // -------------------------------------------------------------------------
-        fn digest_file(path: &Path) -> NyxResult<Vec<u8>> {
-            let mut hasher = blake3::Hasher::new();
-            let mut file = fs::File::open(path)?;
-            std::io::copy(&mut file, &mut hasher)?;
-            Ok(hasher.finalize().as_bytes().to_vec())
+        pub fn clear(&self) -> NyxResult<()> {
+            self.store.clear()
+        }
+
+        pub fn vacuum(&self) -> NyxResult<()> {
+            self.store.vacuum()
         }
     }
 }
@@ -338,12 +1488,18 @@ fn replace_issues_and_query_back() {
             severity: "High",
             line: 3,
             col: 7,
+            caps: 0,
+            fingerprint: String::new(),
+            spans_json: "[]".into(),
         },
         index::IssueRow {
             rule_id: "X2",
             severity: "Low",
             line: 4,
             col: 1,
+            caps: 0,
+            fingerprint: String::new(),
+            spans_json: "[]".into(),
         },
     ];
     idx.replace_issues(fid, issues.clone()).unwrap();
@@ -362,6 +1518,86 @@ fn replace_issues_and_query_back() {
     );
 }
 
+#[test]
+fn query_issues_filters_by_severity_rule_and_prefix() {
+    let td = tempfile::tempdir().unwrap();
+    let db = td.path().join("nyx.sqlite");
+    let file_a = td.path().join("a.rs");
+    let file_b = td.path().join("b.rs");
+    std::fs::write(&file_a, "fn a() {}").unwrap();
+    std::fs::write(&file_b, "fn b() {}").unwrap();
+
+    let pool = index::Indexer::init(&db).unwrap();
+    let mut idx = index::Indexer::from_pool("proj", &pool).unwrap();
+
+    let fid_a = idx.upsert_file(&file_a).unwrap();
+    idx.replace_issues(
+        fid_a,
+        [
+            index::IssueRow {
+                rule_id: "sql_injection",
+                severity: "High",
+                line: 1,
+                col: 1,
+                caps: 0,
+                fingerprint: String::new(),
+                spans_json: "[]".into(),
+            },
+            index::IssueRow {
+                rule_id: "weak_hash_md5",
+                severity: "Low",
+                line: 2,
+                col: 1,
+                caps: 0,
+                fingerprint: String::new(),
+                spans_json: "[]".into(),
+            },
+        ],
+    )
+    .unwrap();
+
+    let fid_b = idx.upsert_file(&file_b).unwrap();
+    idx.replace_issues(
+        fid_b,
+        [index::IssueRow {
+            rule_id: "sql_injection",
+            severity: "Medium",
+            line: 5,
+            col: 1,
+            caps: 0,
+            fingerprint: String::new(),
+            spans_json: "[]".into(),
+        }],
+    )
+    .unwrap();
+
+    let high_only = idx
+        .query_issues(&index::IssueFilter {
+            min_severity: crate::patterns::Severity::High,
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(high_only.len(), 1);
+    assert_eq!(high_only[0].id, "sql_injection");
+
+    let by_rule = idx
+        .query_issues(&index::IssueFilter {
+            rule_like: Some("%sql%".into()),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(by_rule.len(), 2);
+
+    let by_prefix = idx
+        .query_issues(&index::IssueFilter {
+            path_prefix: Some(file_b.to_string_lossy().into_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(by_prefix.len(), 1);
+    assert_eq!(by_prefix[0].line, 5);
+}
+
 #[test]
 fn clear_and_vacuum_reset_tables() {
     let td = tempfile::tempdir().unwrap();
@@ -378,3 +1614,214 @@ fn clear_and_vacuum_reset_tables() {
     idx.vacuum().unwrap();
     assert!(idx.get_files("proj").unwrap().is_empty());
 }
+
+#[test]
+fn in_memory_store_round_trips_without_disk() {
+    let td = tempfile::tempdir().unwrap();
+    let file = td.path().join("mem.rs");
+    std::fs::write(&file, "fn main() {}").unwrap();
+
+    let mut idx = index::Indexer::in_memory("proj");
+
+    assert!(idx.should_scan(&file).unwrap());
+    let fid = idx.upsert_file(&file).unwrap();
+    assert!(!idx.should_scan(&file).unwrap());
+
+    idx.replace_issues(
+        fid,
+        [index::IssueRow {
+            rule_id: "mem_rule",
+            severity: "High",
+            line: 1,
+            col: 1,
+            caps: 0,
+            fingerprint: String::new(),
+            spans_json: "[]".into(),
+        }],
+    )
+    .unwrap();
+
+    let stored = idx.get_issues_from_file(&file).unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_eq!(stored[0].id, "mem_rule");
+
+    assert_eq!(idx.get_files("proj").unwrap(), vec![file.clone()]);
+
+    idx.clear().unwrap();
+    assert!(idx.get_issues_from_file(&file).unwrap().is_empty());
+}
+
+#[test]
+fn find_similar_ranks_matching_summary_highest() {
+    use crate::embed::HashingEmbedder;
+    use crate::summary::FuncSummary;
+    use std::path::Path;
+
+    let embedder = HashingEmbedder::new(64);
+    let mut idx = index::Indexer::in_memory("proj");
+
+    idx.upsert_summary(
+        "proj",
+        Path::new("a.rs"),
+        "hash-a",
+        &FuncSummary {
+            name: "handle_login".into(),
+        },
+        &embedder,
+    )
+    .unwrap();
+    idx.upsert_summary(
+        "proj",
+        Path::new("b.rs"),
+        "hash-b",
+        &FuncSummary {
+            name: "render_homepage".into(),
+        },
+        &embedder,
+    )
+    .unwrap();
+
+    let query = embedder.embed("handle_login");
+    let top = idx.find_similar("proj", &query, 1).unwrap();
+
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].0.name, "handle_login");
+    assert!(top[0].1 > 0.99, "exact match should score ~1.0, got {}", top[0].1);
+}
+
+/// Builds an `IssueRow` for `d`, computing its fingerprint the same way
+/// `commands::scan::scan_with_index_parallel` does, given this diag's
+/// `ordinal` (from [`crate::commands::scan::fingerprint_ordinals`]).
+fn issue_row_for<'a>(d: &'a crate::commands::scan::Diag, ordinal: usize) -> index::IssueRow<'a> {
+    index::IssueRow {
+        rule_id: &d.id,
+        severity: d.severity.as_db_str(),
+        line: d.line as i64,
+        col: d.col as i64,
+        caps: d.caps.bits(),
+        fingerprint: d.fingerprint(ordinal),
+        spans_json: "[]".into(),
+    }
+}
+
+#[test]
+fn diff_baseline_pairs_duplicate_location_findings_one_for_one() {
+    use crate::commands::scan::{Diag, fingerprint_ordinals};
+    use crate::patterns::Severity;
+
+    let td = tempfile::tempdir().unwrap();
+    let db = td.path().join("nyx.sqlite");
+    let file = td.path().join("dup.rs");
+    std::fs::write(&file, "fn main() {}").unwrap();
+
+    let pool = index::Indexer::init(&db).unwrap();
+    let mut idx = index::Indexer::from_pool("proj", &pool).unwrap();
+    let fid = idx.upsert_file(&file).unwrap();
+
+    let path = file.to_string_lossy().into_owned();
+    let diag = |id: &str, severity: Severity| Diag {
+        path: path.clone(),
+        line: 5,
+        col: 2,
+        severity,
+        id: id.to_string(),
+        end_line: None,
+        end_col: None,
+        title: None,
+        spans: vec![],
+        caps: crate::labels::Cap::empty(),
+    };
+
+    // Two distinct rules firing on the exact same line/col — each baseline
+    // row must pair off with its own rule's finding, so dropping one rule
+    // reads as exactly one fix and nothing new.
+    let diags = vec![diag("r1", Severity::High), diag("r2", Severity::Low)];
+    let ordinals = fingerprint_ordinals(&diags);
+    let rows: Vec<index::IssueRow> = diags
+        .iter()
+        .zip(&ordinals)
+        .map(|(d, &ord)| issue_row_for(d, ord))
+        .collect();
+    idx.replace_issues(fid, rows).unwrap();
+    idx.create_baseline("base").unwrap();
+
+    // "r2" got fixed; "r1" still fires at the exact same location.
+    let remaining = [diags[0].clone()];
+    let remaining_ordinals = fingerprint_ordinals(&remaining);
+    let rows: Vec<index::IssueRow> = remaining
+        .iter()
+        .zip(&remaining_ordinals)
+        .map(|(d, &ord)| issue_row_for(d, ord))
+        .collect();
+    idx.replace_issues(fid, rows).unwrap();
+
+    let diff = idx.diff_baseline("base").unwrap();
+    assert!(diff.new.is_empty());
+    assert_eq!(diff.fixed.len(), 1);
+    assert_eq!(diff.fixed[0].rule_id, "r2");
+}
+
+#[test]
+fn fingerprint_disambiguates_repeated_rule_in_same_file() {
+    use crate::commands::scan::{Diag, fingerprint_ordinals};
+    use crate::patterns::Severity;
+
+    let td = tempfile::tempdir().unwrap();
+    let db = td.path().join("nyx.sqlite");
+    let file = td.path().join("repeat.rs");
+    std::fs::write(&file, "fn main() {}").unwrap();
+
+    let pool = index::Indexer::init(&db).unwrap();
+    let mut idx = index::Indexer::from_pool("proj", &pool).unwrap();
+    let fid = idx.upsert_file(&file).unwrap();
+
+    let path = file.to_string_lossy().into_owned();
+    // Same rule, same file, two different locations — without an ordinal
+    // disambiguator these would hash identically (no spans to tell them
+    // apart) and collide in `diff_against_baseline`'s fingerprint pass.
+    let diags = vec![
+        Diag {
+            path: path.clone(),
+            line: 3,
+            col: 1,
+            severity: Severity::Medium,
+            id: "dup_rule".to_string(),
+            end_line: None,
+            end_col: None,
+            title: None,
+            spans: vec![],
+            caps: crate::labels::Cap::empty(),
+        },
+        Diag {
+            path: path.clone(),
+            line: 9,
+            col: 1,
+            severity: Severity::Medium,
+            id: "dup_rule".to_string(),
+            end_line: None,
+            end_col: None,
+            title: None,
+            spans: vec![],
+            caps: crate::labels::Cap::empty(),
+        },
+    ];
+    let ordinals = fingerprint_ordinals(&diags);
+    assert_ne!(
+        diags[0].fingerprint(ordinals[0]),
+        diags[1].fingerprint(ordinals[1])
+    );
+
+    let rows: Vec<index::IssueRow> = diags
+        .iter()
+        .zip(&ordinals)
+        .map(|(d, &ord)| issue_row_for(d, ord))
+        .collect();
+    idx.replace_issues(fid, rows).unwrap();
+    idx.create_baseline("base").unwrap();
+
+    // Unchanged rescan: both findings must still match via the fingerprint
+    // pass, not collapse onto a single shared fingerprint.
+    let diff = idx.diff_baseline("base").unwrap();
+    assert!(diff.new.is_empty());
+    assert!(diff.fixed.is_empty());
+}
@@ -0,0 +1,115 @@
+use crate::cfg::{Cfg, EdgeFilter};
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+/// Rendering knobs for [`render_dot`]. The defaults reproduce the full
+/// node/edge dump `dump_graph` writes to the `taint` log target, just as a
+/// GraphViz file instead of `debug!` lines.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DotOptions {
+    /// Omit the `{:?}` `NodeInfo` dump from node labels; nodes are drawn
+    /// with just their index.
+    pub no_node_labels: bool,
+
+    /// Omit the `EdgeKind` dump from edge labels; edges are drawn unlabeled.
+    pub no_edge_labels: bool,
+
+    /// Render for a dark background: `bgcolor="black"`, white font, and
+    /// white node/edge outlines instead of GraphViz's defaults.
+    pub dark_theme: bool,
+}
+
+/// Escapes a value for use inside a DOT quoted string: backslashes and
+/// double quotes are escaped, and newlines become `\l` so multi-line labels
+/// (the `{:?}` dump of a `NodeInfo`/`EdgeKind`) render left-aligned instead
+/// of collapsing onto one line.
+fn escape_label(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\l"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Writes `g` to `w` as a GraphViz DOT digraph, so it can be piped straight
+/// into `dot`/`xdot` instead of read back out of `debug!` log lines.
+///
+/// `filter` narrows the output the same way `dump_graph`'s does: an
+/// `EdgeFilter` query (e.g. `"arg & fn_foo -> sink"`) restricts the rendered
+/// edges to those whose source/target debug strings match, and only the
+/// nodes those edges touch are drawn. `None` renders the whole graph.
+pub fn render_dot<W: Write>(
+    g: &Cfg,
+    w: &mut W,
+    opts: DotOptions,
+    filter: Option<&str>,
+) -> io::Result<()> {
+    let edge_filter = filter.map(EdgeFilter::new);
+
+    let matching_edges: Vec<_> = g
+        .edge_references()
+        .filter(|e| {
+            edge_filter.as_ref().map_or(true, |ef| {
+                ef.matches(
+                    &format!("{:?}", g[e.source()]),
+                    &format!("{:?}", g[e.target()]),
+                )
+            })
+        })
+        .collect();
+
+    let shown_nodes: HashSet<NodeIndex> = matching_edges
+        .iter()
+        .flat_map(|e| [e.source(), e.target()])
+        .collect();
+
+    writeln!(w, "digraph taint {{")?;
+    if opts.dark_theme {
+        writeln!(w, "  bgcolor=\"black\";")?;
+        writeln!(w, "  fontcolor=\"white\";")?;
+        writeln!(
+            w,
+            "  node [color=\"white\", fontcolor=\"white\"];"
+        )?;
+        writeln!(
+            w,
+            "  edge [color=\"white\", fontcolor=\"white\"];"
+        )?;
+    }
+
+    for (idx, info) in g.node_references() {
+        if edge_filter.is_some() && !shown_nodes.contains(&idx) {
+            continue;
+        }
+        if opts.no_node_labels {
+            writeln!(w, "  N{} [label=\"N{}\"];", idx.index(), idx.index())?;
+        } else {
+            let label = escape_label(&format!("N{}: {:?}", idx.index(), info));
+            writeln!(w, "  N{} [label=\"{}\"];", idx.index(), label)?;
+        }
+    }
+
+    for e in &matching_edges {
+        if opts.no_edge_labels {
+            writeln!(w, "  N{} -> N{};", e.source().index(), e.target().index())?;
+        } else {
+            let label = escape_label(&format!("{:?}", e.weight()));
+            writeln!(
+                w,
+                "  N{} -> N{} [label=\"{}\"];",
+                e.source().index(),
+                e.target().index(),
+                label
+            )?;
+        }
+    }
+
+    writeln!(w, "}}")
+}
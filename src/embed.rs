@@ -0,0 +1,68 @@
+use std::hash::{Hash, Hasher};
+
+/// Produces a fixed-dimension embedding vector for a piece of text, so
+/// `IndexStore::find_similar` can compare `FuncSummary`s by cosine
+/// similarity instead of just by content hash.
+///
+/// Kept as a trait so a real model-backed embedder can be swapped in later
+/// without the storage layer caring how the vectors were produced.
+pub trait Embedder: Send + Sync {
+    /// Dimensionality of vectors this embedder produces. Stored alongside
+    /// each embedding so lookups can skip rows from a different model.
+    fn dim(&self) -> usize;
+    fn embed(&self, text: &str) -> Vec<f32>;
+}
+
+/// A dependency-free default: a hashing-trick bag-of-trigrams embedder.
+/// Deterministic, fast, and ships no model weights — good enough to
+/// exercise `find_similar` and to fall back on when no real model is
+/// configured.
+pub struct HashingEmbedder {
+    dim: usize,
+}
+
+impl HashingEmbedder {
+    pub fn new(dim: usize) -> Self {
+        Self { dim }
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn dim(&self) -> usize {
+        self.dim
+    }
+
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut v = vec![0f32; self.dim.max(1)];
+        let bytes = text.as_bytes();
+        if bytes.len() < 3 {
+            return v;
+        }
+
+        for w in bytes.windows(3) {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            w.hash(&mut hasher);
+            let bucket = (hasher.finish() as usize) % v.len();
+            v[bucket] += 1.0;
+        }
+
+        v
+    }
+}
+
+#[test]
+fn hashing_embedder_is_deterministic_and_dimension_matches() {
+    let e = HashingEmbedder::new(32);
+    let a = e.embed("fn handle_login(user, pass)");
+    let b = e.embed("fn handle_login(user, pass)");
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 32);
+}
+
+#[test]
+fn hashing_embedder_distinguishes_different_text() {
+    let e = HashingEmbedder::new(32);
+    let a = e.embed("fn handle_login(user, pass)");
+    let b = e.embed("fn render_homepage(ctx)");
+    assert_ne!(a, b);
+}
@@ -19,6 +19,9 @@ pub enum NyxError {
     #[error("tree-sitter error: {0}")]
     TreeSitter(#[from] tree_sitter::LanguageError),
 
+    #[error("JSON serialization error: {0}")]
+    Json(#[from] serde_json::Error),
+
     #[error("connection-pool error: {0}")]
     Pool(#[from] r2d2::Error),
 
@@ -28,6 +31,9 @@ pub enum NyxError {
     #[error("poisoned lock: {0}")]
     Poison(String),
 
+    #[error("invalid rule: {0}")]
+    InvalidRule(String),
+
     #[error(transparent)]
     Other(#[from] Box<dyn StdError + Send + Sync + 'static>),
 
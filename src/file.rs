@@ -60,12 +60,17 @@ pub(crate) fn run_rules_on_file(
     while let Some(m) = matches.next() {
       if let Some(cap) = m.captures.iter().find(|c| c.index == 0) {
         let point = cap.node.start_position();
+        let end_point = cap.node.end_position();
         out.push(Diag {
           path: path.to_string_lossy().into_owned(),
           line: point.row + 1,
           col:  point.column + 1,
           severity: cq.meta.severity,
           id: cq.meta.id.to_owned(),
+          end_line: Some(end_point.row + 1),
+          end_col: Some(end_point.column + 1),
+          title: None,
+          spans: Vec::new(),
         });
       }
     }
@@ -0,0 +1,191 @@
+//! Per-function AST spans and content hashes.
+//!
+//! Whole-file hashing (`database::index::digest_file`) forces a full
+//! rescan on any edit, however small. This module extracts each function's
+//! byte span from the parsed tree and hashes it independently, so a rescan
+//! can diff the new set of function hashes against what's stored in
+//! `file_functions` and only regenerate summaries for the functions that
+//! actually changed — unchanged ones reuse their `function_summaries` row,
+//! keyed by that same hash.
+
+use crate::database::index::{FileFunctionRow, Indexer};
+use crate::errors::NyxResult;
+use crate::utils::ext::lowercase_ext;
+use rayon::prelude::*;
+use std::path::Path;
+use tree_sitter::Node;
+
+/// One function-like definition found in a parsed file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionSpan {
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+    start_byte: usize,
+    end_byte: usize,
+}
+
+/// Node kinds tree-sitter uses for "a function" in each supported
+/// language. Not exhaustive — closures/lambdas are deliberately skipped,
+/// since they don't have a stable name to key a summary on.
+fn is_function_kind(lang: &str, kind: &str) -> bool {
+    match lang {
+        "rust" => matches!(kind, "function_item"),
+        "c" | "cpp" => matches!(kind, "function_definition"),
+        "java" => matches!(kind, "method_declaration" | "constructor_declaration"),
+        "go" => matches!(kind, "function_declaration" | "method_declaration"),
+        "php" => matches!(kind, "function_definition" | "method_declaration"),
+        "python" => matches!(kind, "function_definition"),
+        "typescript" | "javascript" => matches!(kind, "function_declaration" | "method_definition"),
+        "ruby" => matches!(kind, "method"),
+        _ => false,
+    }
+}
+
+fn function_name(node: Node, code: &[u8]) -> String {
+    node.child_by_field_name("name")
+        .and_then(|n| n.utf8_text(code).ok())
+        .unwrap_or("<anonymous>")
+        .to_owned()
+}
+
+fn walk<'a>(node: Node<'a>, lang: &str, code: &'a [u8], out: &mut Vec<FunctionSpan>) {
+    if is_function_kind(lang, node.kind()) {
+        out.push(FunctionSpan {
+            name: function_name(node, code),
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            start_byte: node.start_byte(),
+            end_byte: node.end_byte(),
+        });
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        walk(child, lang, code, out);
+    }
+}
+
+/// Every function-like definition in `tree`, in source order.
+pub fn extract_functions(tree: &tree_sitter::Tree, code: &[u8], lang: &str) -> Vec<FunctionSpan> {
+    let mut out = Vec::new();
+    walk(tree.root_node(), lang, code, &mut out);
+    out
+}
+
+fn hash_span(code: &[u8], span: &FunctionSpan) -> String {
+    blake3::hash(&code[span.start_byte..span.end_byte])
+        .to_hex()
+        .to_string()
+}
+
+/// Hash every span's byte range in parallel — the only part of this pass
+/// whose cost scales with file size, so it's worth spreading across
+/// threads the same way the rest of the scanner's filesystem walk does.
+pub fn hash_functions(code: &[u8], spans: Vec<FunctionSpan>) -> Vec<(String, FunctionSpan)> {
+    spans
+        .into_par_iter()
+        .map(|s| (hash_span(code, &s), s))
+        .collect()
+}
+
+/// Re-parse `path`, diff its function hashes against what `file_id` had
+/// stored, persist the new set, and return only the `(hash, span)` pairs
+/// that are new or changed — the ones that need a summary regenerated.
+pub fn diff_and_store_function_hashes(
+    path: &Path,
+    idx: &mut Indexer,
+    file_id: i64,
+) -> NyxResult<Vec<(String, FunctionSpan)>> {
+    let Some((ts_lang, lang_slug)) = crate::ast::detect_language(lowercase_ext(path)) else {
+        return Ok(Vec::new());
+    };
+
+    let bytes = std::fs::read(path)?;
+    let mut parser = tree_sitter::Parser::new();
+    parser.set_language(&ts_lang)?;
+    let Some(tree) = parser.parse(&bytes, None) else {
+        return Ok(Vec::new());
+    };
+
+    let spans = extract_functions(&tree, &bytes, lang_slug);
+    let hashed = hash_functions(&bytes, spans);
+
+    let previous = idx.get_file_function_hashes(file_id)?;
+    let changed: Vec<(String, FunctionSpan)> = hashed
+        .iter()
+        .filter(|(h, _)| !previous.contains(h))
+        .cloned()
+        .collect();
+
+    let rows: Vec<FileFunctionRow<'_>> = hashed
+        .iter()
+        .map(|(h, s)| FileFunctionRow {
+            fn_hash: h,
+            name: &s.name,
+            start_line: s.start_line as i64,
+            end_line: s.end_line as i64,
+        })
+        .collect();
+    idx.replace_file_functions(file_id, rows)?;
+
+    Ok(changed)
+}
+
+#[test]
+fn extracts_rust_function_spans_in_source_order() {
+    use tree_sitter::{Language, Parser};
+
+    let src = b"fn a() {}\nfn b() { let x = 1; }\n";
+    let mut parser = Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree = parser.parse(src, None).unwrap();
+
+    let spans = extract_functions(&tree, src, "rust");
+    assert_eq!(spans.len(), 2);
+    assert_eq!(spans[0].name, "a");
+    assert_eq!(spans[1].name, "b");
+}
+
+#[test]
+fn unchanged_function_hash_is_stable_changed_one_differs() {
+    use tree_sitter::{Language, Parser};
+
+    let src_a = b"fn a() { 1 }\nfn b() { 2 }\n";
+    let src_b = b"fn a() { 1 }\nfn b() { 3 }\n";
+
+    let mut parser = Parser::new();
+    parser
+        .set_language(&Language::from(tree_sitter_rust::LANGUAGE))
+        .unwrap();
+    let tree_a = parser.parse(&src_a[..], None).unwrap();
+    let tree_b = parser.parse(&src_b[..], None).unwrap();
+
+    let hashes_a = hash_functions(&src_a[..], extract_functions(&tree_a, src_a, "rust"));
+    let hashes_b = hash_functions(&src_b[..], extract_functions(&tree_b, src_b, "rust"));
+
+    assert_eq!(hashes_a[0].0, hashes_b[0].0, "fn a is unchanged");
+    assert_ne!(hashes_a[1].0, hashes_b[1].0, "fn b changed");
+}
+
+#[test]
+fn diff_and_store_reports_only_changed_functions_on_rescan() {
+    use crate::database::index::Indexer;
+
+    let td = tempfile::tempdir().unwrap();
+    let file = td.path().join("f.rs");
+    std::fs::write(&file, "fn a() { 1 }\nfn b() { 2 }\n").unwrap();
+
+    let mut idx = Indexer::in_memory("proj");
+    let file_id = idx.upsert_file(&file).unwrap();
+
+    let first = diff_and_store_function_hashes(&file, &mut idx, file_id).unwrap();
+    assert_eq!(first.len(), 2, "first scan: every function is new");
+
+    std::fs::write(&file, "fn a() { 1 }\nfn b() { 99 }\n").unwrap();
+    let second = diff_and_store_function_hashes(&file, &mut idx, file_id).unwrap();
+    assert_eq!(second.len(), 1, "only `b` changed");
+    assert_eq!(second[0].1.name, "b");
+}
@@ -0,0 +1,195 @@
+use crate::cfg::{Cfg, EdgeKind, NodeInfo};
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// A flattened, serde-encodable snapshot of a [`Cfg`]: node payloads in
+/// index order plus the edge list as dense-index pairs. Persisting this
+/// instead of re-running taint propagation from scratch is what makes
+/// incremental re-analysis possible across runs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedTaintGraph {
+    pub nodes: Vec<NodeInfo>,
+    pub edges: Vec<(usize, usize, EdgeKind)>,
+}
+
+impl SerializedTaintGraph {
+    pub fn from_cfg(g: &Cfg) -> Self {
+        let node_of: Vec<NodeIndex> = g.node_references().map(|(idx, _)| idx).collect();
+        let index_of: HashMap<NodeIndex, usize> = node_of
+            .iter()
+            .enumerate()
+            .map(|(i, &idx)| (idx, i))
+            .collect();
+
+        let nodes = node_of.iter().map(|&idx| g[idx].clone()).collect();
+        let edges = g
+            .edge_references()
+            .map(|e| (index_of[&e.source()], index_of[&e.target()], *e.weight()))
+            .collect();
+
+        SerializedTaintGraph { nodes, edges }
+    }
+}
+
+/// A [`SerializedTaintGraph`] rehydrated for lookups against a prior run:
+/// indexes node payloads both ways (`node_to_index`/`index_to_node`), and
+/// separately remembers which indices were *roots* — nodes with no incoming
+/// edge, analogous to bootstrap/entry outputs — since once the edge list
+/// alone is reloaded there's no way to tell "always had no predecessor"
+/// apart from "its one predecessor was removed"; tracking roots up front
+/// keeps that available through `is_root`. `diff` itself compares node
+/// payloads and edges directly and does not consult the roots.
+pub struct PreviousTaintGraph {
+    pub nodes: Vec<NodeInfo>,
+    pub edges: HashSet<(usize, usize, EdgeKind)>,
+    node_to_index: HashMap<NodeInfo, usize>,
+    index_to_node: HashMap<usize, NodeInfo>,
+    roots: HashSet<usize>,
+}
+
+impl PreviousTaintGraph {
+    pub fn from_serialized(g: SerializedTaintGraph) -> Self {
+        let has_incoming: HashSet<usize> = g.edges.iter().map(|&(_, j, _)| j).collect();
+        let roots: HashSet<usize> = (0..g.nodes.len())
+            .filter(|i| !has_incoming.contains(i))
+            .collect();
+
+        let node_to_index: HashMap<NodeInfo, usize> = g
+            .nodes
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, n)| (n, i))
+            .collect();
+        let index_to_node: HashMap<usize, NodeInfo> =
+            g.nodes.iter().cloned().enumerate().map(|(i, n)| (i, n)).collect();
+
+        PreviousTaintGraph {
+            nodes: g.nodes,
+            edges: g.edges.into_iter().collect(),
+            node_to_index,
+            index_to_node,
+            roots,
+        }
+    }
+
+    pub fn node_to_index(&self, node: &NodeInfo) -> Option<usize> {
+        self.node_to_index.get(node).copied()
+    }
+
+    pub fn index_to_node(&self, index: usize) -> Option<&NodeInfo> {
+        self.index_to_node.get(&index)
+    }
+
+    pub fn is_root(&self, index: usize) -> bool {
+        self.roots.contains(&index)
+    }
+}
+
+/// What changed between two taint-graph snapshots. Callers use this to
+/// re-run taint propagation only over the added/removed subgraph instead of
+/// the whole program.
+#[derive(Debug, Clone, Default)]
+pub struct TaintGraphDiff {
+    pub added_nodes: Vec<NodeInfo>,
+    pub removed_nodes: Vec<NodeInfo>,
+    pub added_edges: Vec<(usize, usize, EdgeKind)>,
+    pub removed_edges: Vec<(usize, usize, EdgeKind)>,
+}
+
+/// Diffs `prev` (a prior run, already indexed via `PreviousTaintGraph`)
+/// against `current` (this run's freshly serialized graph).
+pub fn diff(prev: &PreviousTaintGraph, current: &SerializedTaintGraph) -> TaintGraphDiff {
+    let prev_nodes: HashSet<&NodeInfo> = prev.nodes.iter().collect();
+    let current_nodes: HashSet<&NodeInfo> = current.nodes.iter().collect();
+
+    let added_nodes = current_nodes
+        .difference(&prev_nodes)
+        .map(|&n| n.clone())
+        .collect();
+    let removed_nodes = prev_nodes
+        .difference(&current_nodes)
+        .map(|&n| n.clone())
+        .collect();
+
+    let current_edges: HashSet<(usize, usize, EdgeKind)> =
+        current.edges.iter().copied().collect();
+    let added_edges = current_edges.difference(&prev.edges).copied().collect();
+    let removed_edges = prev.edges.difference(&current_edges).copied().collect();
+
+    TaintGraphDiff {
+        added_nodes,
+        removed_nodes,
+        added_edges,
+        removed_edges,
+    }
+}
+
+#[test]
+fn round_trip_through_json_preserves_graph() {
+    use crate::cfg::StmtKind;
+
+    let mut g = Cfg::new();
+    let mk = |kind| NodeInfo {
+        kind,
+        span: (0, 0),
+        label: None,
+        defines: None,
+        uses: Vec::new(),
+        callee: None,
+        call_args: Vec::new(),
+    };
+    let a = g.add_node(mk(StmtKind::Entry));
+    let b = g.add_node(mk(StmtKind::Exit));
+    g.add_edge(a, b, EdgeKind::Seq);
+
+    let serialized = SerializedTaintGraph::from_cfg(&g);
+    let json = serde_json::to_string(&serialized).unwrap();
+    let round_tripped: SerializedTaintGraph = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(round_tripped.nodes.len(), 2);
+    assert_eq!(round_tripped.edges, vec![(0, 1, EdgeKind::Seq)]);
+}
+
+#[test]
+fn diff_reports_added_nodes_and_edges_appended_to_an_unchanged_prefix() {
+    use crate::cfg::StmtKind;
+
+    let mk = |kind| NodeInfo {
+        kind,
+        span: (0, 0),
+        label: None,
+        defines: None,
+        uses: Vec::new(),
+        callee: None,
+        call_args: Vec::new(),
+    };
+
+    let mut prev_g = Cfg::new();
+    let a = prev_g.add_node(mk(StmtKind::Entry));
+    let b = prev_g.add_node(mk(StmtKind::Exit));
+    prev_g.add_edge(a, b, EdgeKind::Seq);
+    let prev = PreviousTaintGraph::from_serialized(SerializedTaintGraph::from_cfg(&prev_g));
+
+    // `Entry` has no predecessor in either run — it's a root.
+    assert!(prev.is_root(prev.node_to_index(&mk(StmtKind::Entry)).unwrap()));
+
+    // `current` keeps the same two nodes at the same indices, then appends
+    // a third node reached from `Exit` — the unchanged prefix should not
+    // show up as added/removed.
+    let mut current_g = Cfg::new();
+    let a2 = current_g.add_node(mk(StmtKind::Entry));
+    let b2 = current_g.add_node(mk(StmtKind::Exit));
+    let c2 = current_g.add_node(mk(StmtKind::Call));
+    current_g.add_edge(a2, b2, EdgeKind::Seq);
+    current_g.add_edge(b2, c2, EdgeKind::Seq);
+    let current = SerializedTaintGraph::from_cfg(&current_g);
+
+    let d = diff(&prev, &current);
+    assert_eq!(d.added_nodes, vec![mk(StmtKind::Call)]);
+    assert!(d.removed_nodes.is_empty());
+    assert_eq!(d.added_edges, vec![(1, 2, EdgeKind::Seq)]);
+    assert!(d.removed_edges.is_empty());
+}
@@ -0,0 +1,79 @@
+use crate::labels::{Cap, DataLabel, Kind, LabelRule};
+use phf::{Map, phf_map};
+
+/// Reframes three of `patterns::java::PATTERNS`' standalone AST-query checks
+/// as `DataLabel` rules so they feed the same `Source`/`Sanitizer`/`Sink`
+/// taint engine as Rust/JS, instead of firing as one-shot matches decoupled
+/// from dataflow. `insecure_random`/`thread_stop`/`class_for_name` stay as
+/// plain patterns — they're not taint sinks, there's nothing upstream of
+/// them to track.
+pub static RULES: &[LabelRule] = &[
+    // ─────────── Sources ───────────
+    LabelRule {
+        // `ObjectInputStream#readObject()` hands the caller a value built
+        // from bytes it didn't write — an attacker-controlled source just
+        // like `std::env::var`, not a sink.
+        matchers: &["readObject"],
+        label: DataLabel::Source(Cap::all()),
+    },
+    LabelRule {
+        // A Servlet `HttpServletRequest`'s parameters/headers/query string —
+        // the realistic "attacker-controlled input" most Java web code
+        // actually reads, the same role `req.query`/`req.body`/`req.params`
+        // play in `javascript::RULES`. Without this, nothing ever taints
+        // the `userInput` in `Runtime.getRuntime().exec(userInput)` or
+        // `stmt.executeQuery("..." + userInput)`.
+        matchers: &["getParameter", "getHeader", "getQueryString"],
+        label: DataLabel::Source(Cap::all()),
+    },
+    // ─────────── Sinks ─────────────
+    LabelRule {
+        // `Runtime.getRuntime().exec(...)` / `ProcessBuilder#start()`.
+        matchers: &["exec", "start"],
+        label: DataLabel::Sink(Cap::SHELL_ESCAPE),
+    },
+    LabelRule {
+        // String-concatenated SQL handed to `Statement#execute*`.
+        matchers: &["executeQuery", "executeUpdate", "execute"],
+        label: DataLabel::Sink(Cap::SQL_ESCAPE),
+    },
+];
+
+pub static KINDS: Map<&'static str, Kind> = phf_map! {
+    // control-flow
+    "if_statement"              => Kind::If,
+    "while_statement"           => Kind::While,
+    "for_statement"             => Kind::For,
+    "enhanced_for_statement"    => Kind::For,
+
+    "return_statement"          => Kind::Return,
+    "break_statement"           => Kind::Break,
+    "continue_statement"        => Kind::Continue,
+
+    // structure — `class_body` is treated the same transparent way as
+    // `block`: just recurse into every member and let whichever ones are
+    // `method_declaration`s register their own function summary, the same
+    // way `source_file`/`program` are a flat container of top-level items.
+    "program"                   => Kind::SourceFile,
+    "block"                     => Kind::Block,
+    "class_declaration"         => Kind::Block,
+    "class_body"                => Kind::Block,
+    "method_declaration"        => Kind::Function,
+    "constructor_declaration"   => Kind::Function,
+
+    // data-flow
+    "method_invocation"            => Kind::CallMethod,
+    "local_variable_declaration"   => Kind::CallWrapper,
+    "expression_statement"         => Kind::CallWrapper,
+    "assignment_expression"        => Kind::Assignment,
+
+    // trivia
+    "line_comment"       => Kind::Trivia,
+    "block_comment"      => Kind::Trivia,
+    ";" => Kind::Trivia, "," => Kind::Trivia,
+    "(" => Kind::Trivia, ")" => Kind::Trivia,
+    "{" => Kind::Trivia, "}" => Kind::Trivia,
+    "import_declaration"  => Kind::Trivia,
+    "package_declaration" => Kind::Trivia,
+    "modifiers"           => Kind::Trivia,
+};
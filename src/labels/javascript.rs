@@ -1,8 +1,87 @@
-use crate::labels::{Cap, DataLabel, LabelRule};
+use crate::labels::{Cap, DataLabel, Kind, LabelRule};
+use phf::{Map, phf_map};
 
-// TODO: refactor this 
 pub static RULES: &[LabelRule] = &[
-  LabelRule { matchers: &["document.location", "window.location"], label: DataLabel::Source(Cap::all()), },
-  LabelRule { matchers: &["JSON.parse"],       label: DataLabel::Sanitizer(Cap::JSON_PARSE) },
-  LabelRule { matchers: &["eval"],             label: DataLabel::Sink(Cap::SHELL_ESCAPE) },
+    // ─────────── Sources ───────────
+    LabelRule {
+        matchers: &["document.location", "window.location"],
+        label: DataLabel::Source(Cap::all()),
+    },
+    LabelRule {
+        // Node's `process.env` and an Express `req`'s `query`/`body`/`params`
+        // — the usual entry points for attacker-controlled data in a JS/Node
+        // service, the same role `std::env::var`/`fs::read_to_string` play
+        // in `rust::RULES`.
+        matchers: &["process.env", "req.query", "req.body", "req.params"],
+        label: DataLabel::Source(Cap::all()),
+    },
+    // ───────── Sanitizers ──────────
+    LabelRule {
+        matchers: &["JSON.parse"],
+        label: DataLabel::Sanitizer(Cap::JSON_PARSE),
+    },
+    LabelRule {
+        matchers: &["encodeURIComponent", "encodeURI"],
+        label: DataLabel::Sanitizer(Cap::URL_ENCODE),
+    },
+    LabelRule {
+        matchers: &["escapeHtml", "sanitize_html"],
+        label: DataLabel::Sanitizer(Cap::HTML_ESCAPE),
+    },
+    // ─────────── Sinks ─────────────
+    LabelRule {
+        matchers: &["eval"],
+        label: DataLabel::Sink(Cap::SHELL_ESCAPE),
+    },
+    LabelRule {
+        matchers: &["child_process.exec", "child_process.execSync", "exec", "execSync"],
+        label: DataLabel::Sink(Cap::SHELL_ESCAPE),
+    },
+    LabelRule {
+        matchers: &["innerHTML", "document.write"],
+        label: DataLabel::Sink(Cap::HTML_ESCAPE),
+    },
+    LabelRule {
+        matchers: &["query", "execute"],
+        label: DataLabel::Sink(Cap::SQL_ESCAPE),
+    },
 ];
+
+pub static KINDS: Map<&'static str, Kind> = phf_map! {
+    // control-flow
+    "if_statement"          => Kind::If,
+    "while_statement"       => Kind::While,
+    "for_statement"         => Kind::For,
+    "for_in_statement"      => Kind::For,
+
+    "return_statement"      => Kind::Return,
+    "break_statement"       => Kind::Break,
+    "continue_statement"    => Kind::Continue,
+
+    // structure
+    "program"               => Kind::SourceFile,
+    "statement_block"       => Kind::Block,
+    "function_declaration"  => Kind::Function,
+    "function_expression"   => Kind::Function,
+    "arrow_function"        => Kind::Function,
+    "method_definition"     => Kind::Function,
+
+    // data-flow — JS has one `call_expression` node for both plain calls and
+    // `recv.method()` calls (the callee is a `member_expression` either way),
+    // so unlike Rust there's no separate `CallMethod` node kind to register:
+    // `push_node`'s `Kind::CallFn` arm reads the whole `function` field text
+    // verbatim, which already comes out correctly dotted (`"recv.method"`).
+    "call_expression"       => Kind::CallFn,
+    "lexical_declaration"   => Kind::CallWrapper,   // `let`/`const`
+    "variable_declaration"  => Kind::CallWrapper,   // `var`
+    "expression_statement"  => Kind::CallWrapper,
+    "assignment_expression" => Kind::Assignment,
+
+    // trivia
+    "comment"            => Kind::Trivia,
+    ";" => Kind::Trivia, "," => Kind::Trivia,
+    "(" => Kind::Trivia, ")" => Kind::Trivia,
+    "{" => Kind::Trivia, "}" => Kind::Trivia,
+    "import_statement"   => Kind::Trivia,
+    "export_statement"   => Kind::Trivia,
+};
@@ -1,10 +1,13 @@
+mod java;
 mod javascript;
 mod rust;
 
 use bitflags::bitflags;
 use once_cell::sync::Lazy;
 use phf::Map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::HashMap;
+use std::sync::RwLock;
 
 /// A single rule: if the AST text equals (or ends with) one of the `matchers`,
 /// the node gets `label`.
@@ -15,21 +18,122 @@ pub struct LabelRule {
 }
 
 bitflags! {
+    // `u64` rather than the built-ins' original `u8` — bits 7..=63 aren't
+    // named here at all, they're handed out at runtime by `CAP_INTERNER`
+    // (see below) to capability tags a user rule names that aren't one of
+    // these seven, so a config can teach Nyx about its own capability
+    // classes (e.g. `"LDAP_ESCAPE"`) without a recompile or running out of
+    // room the way the old 8-bit set would have after one or two more.
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct Cap: u8 {
+    pub struct Cap: u64 {
         const ENV_VAR      = 0b0000_0001;
         const HTML_ESCAPE  = 0b0000_0010;
         const SHELL_ESCAPE = 0b0000_0100;
         const URL_ENCODE   = 0b0000_1000;
         const JSON_PARSE   = 0b0001_0000;
         const FILE_IO      = 0b0010_0000;
+        const SQL_ESCAPE   = 0b0100_0000;
         // todo: add more if needed
     }
 }
 
+/// Bit position handed out next by [`Cap::intern`]; bits `0..=6` are the
+/// named built-ins above, so the interner starts at `7` and can hand out up
+/// to bit `63` before `Cap`'s backing `u64` runs out.
+const FIRST_INTERNED_BIT: u32 = 7;
+
+/// Maps a user-supplied capability tag name (from `[[scanner.label_rules]]`,
+/// see [`install_user_rules`]) that isn't one of the built-in names to a
+/// stable bit position, assigning a fresh one the first time each name is
+/// seen. Shared process-wide rather than per-config-layer so the same tag
+/// name always gets the same bit even if it's declared in more than one
+/// layered `nyx.local` (project config, then a parent directory's).
+static CAP_INTERNER: Lazy<RwLock<HashMap<String, u32>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+// Implemented by hand rather than derived: bitflags' generated struct wraps
+// its bits in an internal type, so `Hash`/`Serialize`/`Deserialize` go
+// through `.bits()`/`from_bits_truncate()` for a stable, plain-integer
+// representation instead of relying on derive-forwarding into that wrapper.
+impl std::hash::Hash for Cap {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+impl Serialize for Cap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Cap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bits = u64::deserialize(deserializer)?;
+        Ok(Cap::from_bits_truncate(bits))
+    }
+}
+
+impl Cap {
+    /// Parse a single capability flag from its bitflag constant name (e.g.
+    /// `"SHELL_ESCAPE"`), case-insensitively, plus `"ALL"` for `Cap::all()`.
+    /// Used by runtime-loaded label rules (see [`install_user_rules`]), where
+    /// a capability arrives as a plain string rather than a `Cap::FOO` path.
+    /// Unlike [`Cap::intern`], this never assigns a *new* bit — it only
+    /// recognizes the compiled-in names.
+    pub fn from_name(name: &str) -> Option<Cap> {
+        match name.to_ascii_uppercase().as_str() {
+            "ENV_VAR" => Some(Cap::ENV_VAR),
+            "HTML_ESCAPE" => Some(Cap::HTML_ESCAPE),
+            "SHELL_ESCAPE" => Some(Cap::SHELL_ESCAPE),
+            "URL_ENCODE" => Some(Cap::URL_ENCODE),
+            "JSON_PARSE" => Some(Cap::JSON_PARSE),
+            "FILE_IO" => Some(Cap::FILE_IO),
+            "SQL_ESCAPE" => Some(Cap::SQL_ESCAPE),
+            "ALL" => Some(Cap::all()),
+            _ => None,
+        }
+    }
+
+    /// Resolve a capability tag name to its `Cap`, the same as
+    /// [`Cap::from_name`] for one of the seven built-in names, but falling
+    /// back to [`CAP_INTERNER`] for anything else: the first time a given
+    /// name is seen it's handed the next free bit (starting at
+    /// [`FIRST_INTERNED_BIT`]), and every later call for that same name
+    /// (including from a different config layer, or a later `nyx` run in
+    /// the same process) returns the identical bit. `None` only once every
+    /// bit up to 63 is already spoken for — there's no name left to hand
+    /// out, not a parse failure.
+    pub fn intern(name: &str) -> Option<Cap> {
+        if let Some(known) = Self::from_name(name) {
+            return Some(known);
+        }
+
+        let key = name.to_ascii_uppercase();
+        if let Some(&bit) = CAP_INTERNER.read().unwrap().get(&key) {
+            return Some(Cap::from_bits_truncate(1u64 << bit));
+        }
+
+        let mut interner = CAP_INTERNER.write().unwrap();
+        // Another thread may have interned `key` while we waited for the
+        // write lock — recheck before handing out a fresh bit.
+        if let Some(&bit) = interner.get(&key) {
+            return Some(Cap::from_bits_truncate(1u64 << bit));
+        }
+
+        let next = FIRST_INTERNED_BIT + interner.len() as u32;
+        if next > 63 {
+            return None;
+        }
+        interner.insert(key, next);
+        Some(Cap::from_bits_truncate(1u64 << next))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Kind {
     If,
+    Match,
+    Try,
     InfiniteLoop,
     While,
     For,
@@ -49,7 +153,7 @@ pub enum Kind {
     Other,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DataLabel {
     Source(Cap),
     Sanitizer(Cap),
@@ -64,6 +168,8 @@ static REGISTRY: Lazy<HashMap<&'static str, &'static [LabelRule]>> = Lazy::new(|
     m.insert("javascript", javascript::RULES);
     m.insert("js", javascript::RULES);
 
+    m.insert("java", java::RULES);
+
     // add more languages in one line:
     // m.insert("go", go::RULES);
 
@@ -77,8 +183,10 @@ pub(crate) static CLASSIFIERS: Lazy<HashMap<&'static str, FastMap>> = Lazy::new(
     m.insert("rust", &rust::KINDS);
     m.insert("rs", &rust::KINDS);
 
-    // m.insert("javascript",  &javascript::KINDS);
-    // m.insert("js",          &javascript::KINDS);
+    m.insert("javascript", &javascript::KINDS);
+    m.insert("js", &javascript::KINDS);
+
+    m.insert("java", &java::KINDS);
 
     // todo: add more languages
     m
@@ -92,31 +200,103 @@ pub fn lookup(lang: &str, raw: &str) -> Kind {
         .unwrap_or(Kind::Other)
 }
 
-/// Try to classify a piece of syntax text.
+/// Runtime sibling of a built-in [`LabelRule`]: same shape, but with owned
+/// `String` matchers since a rule parsed from a user's rule file at startup
+/// isn't known at compile time. Installed via [`install_user_rules`].
+#[derive(Debug, Clone)]
+pub struct OwnedLabelRule {
+    pub matchers: Vec<String>,
+    pub label: DataLabel,
+}
+
+/// Runtime-loaded sibling of [`REGISTRY`], keyed the same way, consulted by
+/// [`classify`] after the built-in table so a user-supplied source/sink/
+/// sanitizer rule behaves exactly like a compiled-in one. Mirrors how
+/// `query_cache` merges `UserRule`s into the built-in `PATTERNS` tables.
+static USER_RULES: Lazy<RwLock<HashMap<String, Vec<OwnedLabelRule>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Replace the installed set of user-supplied label rules, grouping them by
+/// lowercased language. Called once from `Config::load`/`layered_for_path`
+/// with `ScannerConfig::label_rules`; safe to call again (e.g. across
+/// layered configs, or in tests) since it simply overwrites the previous
+/// set rather than accumulating across calls.
+pub fn install_user_rules(
+    rules: &[crate::utils::config::UserLabelRule],
+) -> crate::errors::NyxResult<()> {
+    let mut by_lang: HashMap<String, Vec<OwnedLabelRule>> = HashMap::new();
+    for r in rules {
+        let mut bits = Cap::empty();
+        for name in &r.caps {
+            // `intern`, not `from_name`: a tag that isn't one of the seven
+            // built-in names still gets a `Cap` here, minted fresh the
+            // first time this process sees it, rather than rejecting the
+            // rule outright — only running out of bits is an error.
+            bits |= Cap::intern(name).ok_or_else(|| {
+                crate::errors::NyxError::InvalidRule(format!(
+                    "label rule for '{}': capability '{name}' exhausted the available bits (max 64 distinct tags)",
+                    r.lang
+                ))
+            })?;
+        }
+        let label = match r.kind {
+            crate::utils::config::LabelKind::Source => DataLabel::Source(bits),
+            crate::utils::config::LabelKind::Sanitizer => DataLabel::Sanitizer(bits),
+            crate::utils::config::LabelKind::Sink => DataLabel::Sink(bits),
+        };
+        by_lang
+            .entry(r.lang.to_ascii_lowercase())
+            .or_default()
+            .push(OwnedLabelRule {
+                matchers: r.matchers.clone(),
+                label,
+            });
+    }
+    *USER_RULES.write().unwrap() = by_lang;
+    Ok(())
+}
+
+/// Whether `raw` (one matcher from a [`LabelRule`]/[`OwnedLabelRule`]) hits
+/// `text_lc`, the already-lowercased call-site text: a trailing `_` makes it
+/// a prefix match (`"sanitize_"`), otherwise it must match as a suffix
+/// immediately preceded by `.`/`:` or the start of the text (so `"execute"`
+/// matches `conn.execute` but not `re_execute`).
+fn matcher_hits(text_lc: &str, raw: &str) -> bool {
+    let m = raw.to_ascii_lowercase();
+    if m.ends_with('_') {
+        text_lc.starts_with(&m)
+    } else if text_lc.ends_with(&m) {
+        let start = text_lc.len() - m.len();
+        start == 0 || matches!(text_lc.as_bytes()[start - 1], b'.' | b':')
+    } else {
+        false
+    }
+}
+
+/// Try to classify a piece of syntax text, checking the built-in
+/// [`REGISTRY`] first and then any [`install_user_rules`]-installed rules
+/// for this language.
 /// `lang` is the canonicalised language key (“rust”, “javascript”, …).
 pub fn classify(lang: &str, text: &str) -> Option<DataLabel> {
     let key = lang.to_ascii_lowercase();
-    let rules = REGISTRY.get(key.as_str())?;
     let head = text.split(['(', '<']).next().unwrap_or("");
-
     let text_lc = head.trim().to_ascii_lowercase();
 
-    for rule in *rules {
-        for raw in rule.matchers {
-            let m = raw.to_ascii_lowercase();
-
-            if m.ends_with('_') {
-                if text_lc.starts_with(&m) {
-                    return Some(rule.label);
-                }
-            } else if text_lc.ends_with(&m) {
-                let start = text_lc.len() - m.len();
-                let ok = start == 0 || matches!(text_lc.as_bytes()[start - 1], b'.' | b':');
-                if ok {
-                    return Some(rule.label);
-                }
+    if let Some(rules) = REGISTRY.get(key.as_str()) {
+        for rule in *rules {
+            if rule.matchers.iter().any(|m| matcher_hits(&text_lc, m)) {
+                return Some(rule.label);
             }
         }
     }
+
+    if let Some(rules) = USER_RULES.read().unwrap().get(&key) {
+        for rule in rules {
+            if rule.matchers.iter().any(|m| matcher_hits(&text_lc, m)) {
+                return Some(rule.label);
+            }
+        }
+    }
+
     None
 }
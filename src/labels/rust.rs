@@ -20,6 +20,13 @@ pub static RULES: &[LabelRule] = &[
         matchers: &["shell_escape::unix::escape", "sanitize_shell"],
         label: DataLabel::Sanitizer(Cap::SHELL_ESCAPE),
     },
+    LabelRule {
+        // Binding a parameter onto a prepared statement before `execute`
+        // clears SQL_ESCAPE the same way `shell_escape` clears SHELL_ESCAPE —
+        // the value no longer reaches the sink as raw concatenated SQL.
+        matchers: &["bind"],
+        label: DataLabel::Sanitizer(Cap::SQL_ESCAPE),
+    },
     // ─────────── Sinks ─────────────
     LabelRule {
         matchers: &[
@@ -36,11 +43,17 @@ pub static RULES: &[LabelRule] = &[
         matchers: &["println", "sink_html"],
         label: DataLabel::Sink(Cap::HTML_ESCAPE),
     },
+    LabelRule {
+        matchers: &["sqlx::query", "rusqlite::connection::execute", "diesel::sql_query", "execute"],
+        label: DataLabel::Sink(Cap::SQL_ESCAPE),
+    },
 ];
 
 pub static KINDS: Map<&'static str, Kind> = phf_map! {
     // control-flow
     "if_expression"        => Kind::If,
+    "match_expression"     => Kind::Match,
+    "try_expression"       => Kind::Try,
     "loop_expression"      => Kind::InfiniteLoop,
     "loop_statement"       => Kind::LoopBody,
     "while_statement"      => Kind::While,
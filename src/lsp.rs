@@ -0,0 +1,281 @@
+//! Minimal Language Server Protocol server for `nyx watch --lsp`: speaks
+//! JSON-RPC 2.0 framed with `Content-Length` headers over stdio (the same
+//! transport `rust-analyzer` and every `vscode-languageserver` backend
+//! use), reusing `crate::ast::run_rules_on_bytes` against each document's
+//! in-editor buffer — not what's last saved to disk — so diagnostics
+//! update live as the user types, the way rust-analyzer serves diagnostics
+//! continuously rather than via batch runs.
+//!
+//! Only as much of the protocol as diagnostics need is implemented:
+//! `initialize`, `initialized`, `textDocument/didOpen`,
+//! `textDocument/didChange` (full-document sync — the only kind this
+//! advertises, so a well-behaved client never sends incremental ranges),
+//! `textDocument/didSave`, `textDocument/didClose`, and `shutdown`/`exit`.
+//! Everything else is answered with "method not found" (requests) or
+//! silently dropped (notifications), same as any LSP server that hasn't
+//! opted into an optional capability.
+
+use crate::ast::{detect_language, run_rules_on_bytes};
+use crate::commands::scan::Diag;
+use crate::errors::{NyxError, NyxResult};
+use crate::patterns::{self, Severity};
+use crate::utils::ext::lowercase_ext;
+use crate::utils::Config;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Read, Write};
+use std::path::PathBuf;
+
+/// One open document's current buffer — the "shared, reusable analysis
+/// session" the editor drives by sending events, as opposed to `nyx scan`
+/// re-reading the file from disk on every run.
+struct Document {
+    text: String,
+}
+
+/// Read one `Content-Length`-framed JSON-RPC message off `stdin`, or `None`
+/// on a clean EOF (the client closed the pipe without sending `exit`).
+fn read_message(stdin: &mut impl BufRead) -> NyxResult<Option<Value>> {
+    let mut content_length: Option<usize> = None;
+    loop {
+        let mut line = String::new();
+        if stdin.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            break;
+        }
+        if let Some(v) = line.strip_prefix("Content-Length:") {
+            content_length = v.trim().parse().ok();
+        }
+    }
+
+    let Some(len) = content_length else {
+        return Err(NyxError::Msg("LSP message missing Content-Length header".into()));
+    };
+
+    let mut body = vec![0u8; len];
+    stdin.read_exact(&mut body)?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+fn write_message(stdout: &mut impl Write, msg: &Value) -> NyxResult<()> {
+    let body = serde_json::to_vec(msg)?;
+    write!(stdout, "Content-Length: {}\r\n\r\n", body.len())?;
+    stdout.write_all(&body)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Our three-tier severity onto LSP's `DiagnosticSeverity` (1=Error,
+/// 2=Warning, 3=Information, 4=Hint) — mirrors `report::sarif_level`'s
+/// mapping onto SARIF's own three-to-N scale.
+fn lsp_severity(sev: Severity) -> u8 {
+    match sev {
+        Severity::High => 1,
+        Severity::Medium => 2,
+        Severity::Low => 3,
+    }
+}
+
+/// One `Diag` as an LSP `Diagnostic` object. LSP positions are 0-based,
+/// unlike `Diag::line`/`col`'s 1-based convention everywhere else in this
+/// crate, so every coordinate here gets `saturating_sub(1)`.
+fn diag_to_lsp(d: &Diag) -> Value {
+    let end_line = d.end_line.unwrap_or(d.line);
+    let end_col = d.end_col.unwrap_or(d.col + 1);
+    let message = d.title.clone().unwrap_or_else(|| {
+        patterns::describe(&d.id)
+            .map(|p| p.description.to_string())
+            .unwrap_or_else(|| d.id.clone())
+    });
+
+    json!({
+        "range": {
+            "start": { "line": d.line.saturating_sub(1), "character": d.col.saturating_sub(1) },
+            "end": { "line": end_line.saturating_sub(1), "character": end_col.saturating_sub(1) },
+        },
+        "severity": lsp_severity(d.severity),
+        "source": "nyx",
+        "code": d.id,
+        "message": message,
+    })
+}
+
+fn publish_diagnostics(stdout: &mut impl Write, uri: &str, diags: &[Diag]) -> NyxResult<()> {
+    write_message(
+        stdout,
+        &json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": {
+                "uri": uri,
+                "diagnostics": diags.iter().map(diag_to_lsp).collect::<Vec<_>>(),
+            },
+        }),
+    )
+}
+
+/// `file:///foo/bar.rs` -> `/foo/bar.rs`. Good enough for the local-file
+/// URIs every editor sends for files actually open on disk; this server
+/// doesn't need to handle other URI schemes.
+fn path_from_uri(uri: &str) -> PathBuf {
+    PathBuf::from(uri.strip_prefix("file://").unwrap_or(uri))
+}
+
+/// Re-run `run_rules_on_bytes` against `text` (the editor's in-memory
+/// buffer, which may be ahead of what's saved) and push the result as a
+/// `publishDiagnostics` notification. A no-op for a `uri` whose extension
+/// isn't one of `detect_language`'s, same as `run_rules_on_bytes` itself
+/// would just return no diagnostics for it.
+fn analyse_and_publish(stdout: &mut impl Write, uri: &str, text: &str, cfg: &Config) -> NyxResult<()> {
+    let path = path_from_uri(uri);
+    if detect_language(lowercase_ext(&path)).is_none() {
+        return Ok(());
+    }
+    let diags = run_rules_on_bytes(&path, text.as_bytes(), cfg)?;
+    publish_diagnostics(stdout, uri, &diags)
+}
+
+/// Runs the server loop until stdin closes or the client sends `exit`.
+/// Synchronous, single-threaded — one message handled at a time, which is
+/// also why the thread-local `PARSER` `run_rules_on_bytes` relies on is
+/// already the right amount of session reuse here without any extra
+/// plumbing: this whole loop runs on one thread for its entire lifetime.
+pub fn run_server(cfg: &Config) -> NyxResult<()> {
+    let stdin = io::stdin();
+    let mut stdin = stdin.lock();
+    let stdout = io::stdout();
+    let mut stdout = stdout.lock();
+
+    let mut docs: HashMap<String, Document> = HashMap::new();
+
+    while let Some(msg) = read_message(&mut stdin)? {
+        let method = msg.get("method").and_then(Value::as_str).unwrap_or("");
+        let id = msg.get("id").cloned();
+
+        match method {
+            "initialize" => {
+                if let Some(id) = id {
+                    write_message(
+                        &mut stdout,
+                        &json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "result": {
+                                "capabilities": {
+                                    // Full-document sync only — no incremental
+                                    // range tracking, same simplification
+                                    // `run_rules_on_bytes` already makes by
+                                    // reparsing a whole file per call.
+                                    "textDocumentSync": 1,
+                                },
+                            },
+                        }),
+                    )?;
+                }
+            }
+            "initialized" => {}
+            "textDocument/didOpen" => {
+                let doc = &msg["params"]["textDocument"];
+                let uri = doc["uri"].as_str().unwrap_or_default().to_string();
+                let text = doc["text"].as_str().unwrap_or_default().to_string();
+                docs.insert(uri.clone(), Document { text: text.clone() });
+                analyse_and_publish(&mut stdout, &uri, &text, cfg)?;
+            }
+            "textDocument/didChange" => {
+                let params = &msg["params"];
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or_default().to_string();
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|c| c.last())
+                    .and_then(|c| c["text"].as_str())
+                {
+                    docs.insert(uri.clone(), Document { text: text.to_string() });
+                    analyse_and_publish(&mut stdout, &uri, text, cfg)?;
+                }
+            }
+            "textDocument/didSave" => {
+                let uri = msg["params"]["textDocument"]["uri"].as_str().unwrap_or_default().to_string();
+                if let Some(doc) = docs.get(&uri) {
+                    analyse_and_publish(&mut stdout, &uri, &doc.text, cfg)?;
+                }
+            }
+            "textDocument/didClose" => {
+                let uri = msg["params"]["textDocument"]["uri"].as_str().unwrap_or_default();
+                docs.remove(uri);
+            }
+            "shutdown" => {
+                if let Some(id) = id {
+                    write_message(
+                        &mut stdout,
+                        &json!({ "jsonrpc": "2.0", "id": id, "result": Value::Null }),
+                    )?;
+                }
+            }
+            "exit" => break,
+            _ => {
+                // Notifications (no `id`) are silently dropped; requests we
+                // don't implement still get an answer so a client isn't left
+                // waiting on one.
+                if let Some(id) = id {
+                    write_message(
+                        &mut stdout,
+                        &json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": { "code": -32601, "message": format!("method not found: {method}") },
+                        }),
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn path_from_uri_strips_the_file_scheme() {
+    assert_eq!(
+        path_from_uri("file:///home/user/project/src/main.rs"),
+        PathBuf::from("/home/user/project/src/main.rs")
+    );
+    // A bare path (no scheme) passes through unchanged.
+    assert_eq!(path_from_uri("/already/a/path.rs"), PathBuf::from("/already/a/path.rs"));
+}
+
+#[test]
+fn lsp_severity_maps_high_to_lowest_numeric_code() {
+    // LSP's DiagnosticSeverity runs 1=Error..4=Hint, the inverse of how
+    // "higher severity" usually sounds, so High must map to the smallest code.
+    assert_eq!(lsp_severity(Severity::High), 1);
+    assert_eq!(lsp_severity(Severity::Medium), 2);
+    assert_eq!(lsp_severity(Severity::Low), 3);
+}
+
+#[test]
+fn diag_to_lsp_converts_one_based_positions_to_zero_based() {
+    let d = Diag {
+        path: "src/main.rs".to_string(),
+        line: 10,
+        col: 5,
+        severity: Severity::High,
+        id: "shell_injection".to_string(),
+        end_line: Some(10),
+        end_col: Some(9),
+        title: Some("untrusted data reaches shell sink".to_string()),
+        spans: Vec::new(),
+        caps: crate::labels::Cap::empty(),
+    };
+
+    let v = diag_to_lsp(&d);
+    assert_eq!(v["range"]["start"]["line"], 9);
+    assert_eq!(v["range"]["start"]["character"], 4);
+    assert_eq!(v["range"]["end"]["line"], 9);
+    assert_eq!(v["range"]["end"]["character"], 8);
+    assert_eq!(v["severity"], 1);
+    assert_eq!(v["code"], "shell_injection");
+    assert_eq!(v["message"], "untrusted data reaches shell sink");
+}
@@ -1,13 +1,23 @@
 mod ast;
 mod cli;
 mod commands;
+mod daemon;
 mod database;
 mod errors;
+mod lsp;
 mod patterns;
 mod utils;
 mod walk;
 mod cfg;
+mod constprop;
+mod dot;
+mod graphdiff;
 mod labels;
+mod reach;
+mod summary;
+mod embed;
+mod functions;
+mod taint;
 
 use crate::errors::NyxResult;
 use crate::utils::Config;
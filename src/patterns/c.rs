@@ -1,40 +1,59 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
   Pattern {
-    id: "strcpy_call",
-    description: "strcpy() usage",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"strcpy\")) @vuln",
+    id: Cow::Borrowed("strcpy_call"),
+    description: Cow::Borrowed("strcpy() usage"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"strcpy\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "strcat_call",
-    description: "strcat() usage",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"strcat\")) @vuln",
+    id: Cow::Borrowed("strcat_call"),
+    description: Cow::Borrowed("strcat() usage"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"strcat\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "sprintf_call",
-    description: "sprintf() (no length limit)",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"sprintf\")) @vuln",
+    id: Cow::Borrowed("sprintf_call"),
+    description: Cow::Borrowed("sprintf() (no length limit)"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"sprintf\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "gets_call",
-    description: "gets() usage",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"gets\")) @vuln",
+    id: Cow::Borrowed("gets_call"),
+    description: Cow::Borrowed("gets() usage"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"gets\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "scanf_with_percent_s",
-    description: "scanf(\"%s\") without length specifier",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"scanf\") arguments: (argument_list (string_literal) @fmt (#match? @fmt \".*%s.*\"))) @vuln",
+    id: Cow::Borrowed("scanf_with_percent_s"),
+    description: Cow::Borrowed("scanf(\"%s\") without length specifier"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"scanf\") arguments: (argument_list (string_literal) @fmt (#match? @fmt \".*%s.*\"))) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "system_call",
-    description: "system() shell execution",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"system\")) @vuln",
+    id: Cow::Borrowed("system_call"),
+    description: Cow::Borrowed("system() shell execution"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"system\")) @vuln"),
     severity: Severity::Medium,
+    cwe: Some("CWE-78"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
 ];
@@ -1,34 +1,50 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
   Pattern {
-    id: "exec_command",
-    description: "os/exec Command construction",
-    query: "(call_expression function: (selector_expression field: (field_identifier) @f (#eq? @f \"Command\"))) @vuln",
+    id: Cow::Borrowed("exec_command"),
+    description: Cow::Borrowed("os/exec Command construction"),
+    query: Cow::Borrowed("(call_expression function: (selector_expression field: (field_identifier) @f (#eq? @f \"Command\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: Some("CWE-78"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
   Pattern {
-    id: "http_insecure_tls",
-    description: "&http.Transport{TLSClientConfig: &tls.Config{InsecureSkipVerify: true}}",
-    query: "(composite_literal type: (selector_expression field: (field_identifier) @t (#eq? @t \"Transport\")) body: (literal_value (keyed_element key: (identifier) @k (#eq? @k \"TLSClientConfig\") value: (composite_literal body: (literal_value (keyed_element key: (identifier) @ik (#eq? @ik \"InsecureSkipVerify\") value: (true)))))) @vuln",
+    id: Cow::Borrowed("http_insecure_tls"),
+    description: Cow::Borrowed("&http.Transport{TLSClientConfig: &tls.Config{InsecureSkipVerify: true}}"),
+    query: Cow::Borrowed("(composite_literal type: (selector_expression field: (field_identifier) @t (#eq? @t \"Transport\")) body: (literal_value (keyed_element key: (identifier) @k (#eq? @k \"TLSClientConfig\") value: (composite_literal body: (literal_value (keyed_element key: (identifier) @ik (#eq? @ik \"InsecureSkipVerify\") value: (true)))))) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "unsafe_pointer",
-    description: "Use of unsafe.Pointer",
-    query: "(qualified_type type: (selector_expression field: (field_identifier) @f (#eq? @f \"Pointer\"))) @vuln",
+    id: Cow::Borrowed("unsafe_pointer"),
+    description: Cow::Borrowed("Use of unsafe.Pointer"),
+    query: Cow::Borrowed("(qualified_type type: (selector_expression field: (field_identifier) @f (#eq? @f \"Pointer\"))) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "md5_sha1",
-    description: "crypto/md5 or crypto/sha1 usage",
-    query: "(call_expression function: (selector_expression object: (identifier) @pkg (#match? @pkg \"md5|sha1\"))) @vuln",
+    id: Cow::Borrowed("md5_sha1"),
+    description: Cow::Borrowed("crypto/md5 or crypto/sha1 usage"),
+    query: Cow::Borrowed("(call_expression function: (selector_expression object: (identifier) @pkg (#match? @pkg \"md5|sha1\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: Some("CWE-327"),
+    owasp: Some("A02:2021-Cryptographic-Failures"),
+    fix: None,
   },
   Pattern {
-    id: "hardcoded_secret",
-    description: "Hard-coded string that looks like an API key/token",
-    query: "(interpreted_string_literal) @s (#match? @s \"(?i)(api|secret|token|password)[=:]?[ \\t]*[A-Za-z0-9_\\-]{8,}\")",
+    id: Cow::Borrowed("hardcoded_secret"),
+    description: Cow::Borrowed("Hard-coded string that looks like an API key/token"),
+    query: Cow::Borrowed("(interpreted_string_literal) @s (#match? @s \"(?i)(api|secret|token|password)[=:]?[ \\t]*[A-Za-z0-9_\\-]{8,}\")"),
     severity: Severity::Low,
+    cwe: Some("CWE-798"),
+    owasp: Some("A07:2021-Identification-and-Authentication-Failures"),
+    fix: None,
   },
 ];
@@ -1,40 +1,51 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 
+// `runtime_exec` and `sql_concat` used to live here as standalone queries;
+// they're now `labels::java::RULES` entries feeding the taint engine
+// instead (see `labels/java.rs`), so a finding tracks back to where the
+// tainted value actually came from rather than firing on the sink call
+// alone. `object_deserialization` stays here *as well as* being a taint
+// `Source` — deserializing untrusted bytes is itself the CWE-502 bug
+// regardless of whether the result happens to flow anywhere interesting
+// afterward, so a bare `ois.readObject()` must still be reported even when
+// it doesn't reach a sink. The rest of these aren't sinks with anything
+// upstream to track, just a plain "this API is bad" check.
 pub const PATTERNS: &[Pattern] = &[
   Pattern {
-    id: "runtime_exec",
-    description: "Runtime.getRuntime().exec(...) – arbitrary-command execution",
-    query: "(method_invocation object: (method_invocation name: (identifier) @n (#eq? @n \"getRuntime\")) name: (identifier) @id (#eq? @id \"exec\")) @vuln",
+    id: Cow::Borrowed("object_deserialization"),
+    description: Cow::Borrowed("java.io.ObjectInputStream#readObject() deserialization"),
+    query: Cow::Borrowed("(method_invocation name: (identifier) @id (#eq? @id \"readObject\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "class_for_name",
-    description: "Dynamic reflection via Class.forName(...)",
-    query: "(method_invocation object: (identifier) @c (#eq? @c \"Class\") name: (identifier) @id (#eq? @id \"forName\")) @vuln",
+    id: Cow::Borrowed("class_for_name"),
+    description: Cow::Borrowed("Dynamic reflection via Class.forName(...)"),
+    query: Cow::Borrowed("(method_invocation object: (identifier) @c (#eq? @c \"Class\") name: (identifier) @id (#eq? @id \"forName\")) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "object_deserialization",
-    description: "java.io.ObjectInputStream#readObject() deserialization",
-    query: "(method_invocation object: (identifier) @o (#eq? @o \"ObjectInputStream\") name: (identifier) @id (#eq? @id \"readObject\")) @vuln",
-    severity: Severity::High,
-  },
-  Pattern {
-    id: "insecure_random",
-    description: "java.util.Random used where SecureRandom is expected",
-    query: "(object_creation_expression type: (identifier) @t (#eq? @t \"Random\")) @vuln",
+    id: Cow::Borrowed("insecure_random"),
+    description: Cow::Borrowed("java.util.Random used where SecureRandom is expected"),
+    query: Cow::Borrowed("(object_creation_expression type: (identifier) @t (#eq? @t \"Random\")) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "thread_stop",
-    description: "Deprecated Thread.stop() invocation",
-    query: "(method_invocation name: (identifier) @id (#eq? @id \"stop\") object: (identifier) @obj (#eq? @obj \"Thread\")) @vuln",
+    id: Cow::Borrowed("thread_stop"),
+    description: Cow::Borrowed("Deprecated Thread.stop() invocation"),
+    query: Cow::Borrowed("(method_invocation name: (identifier) @id (#eq? @id \"stop\") object: (identifier) @obj (#eq? @obj \"Thread\")) @vuln"),
     severity: Severity::Low,
-  },
-  Pattern {
-    id: "sql_concat",
-    description: "SQL built with string concatenation",
-    query: "(method_invocation name: (identifier) @id (#match? @id \"execute(Query|Update)?\") arguments: (argument_list (binary_expression) @concat)) @vuln",
-    severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
 ];
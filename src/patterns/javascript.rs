@@ -1,94 +1,131 @@
-use crate::patterns::{Pattern, Severity};
+use crate::patterns::{Fix, Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
   Pattern {
-    id: "eval_call",
-    description: "Use of eval()",
-    query: "(call_expression function: (identifier) @id (#eq? @id \"eval\")) @vuln",
+    id: Cow::Borrowed("eval_call"),
+    description: Cow::Borrowed("Use of eval()"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"eval\")) @vuln"),
     severity: Severity::High,
+    cwe: Some("CWE-95"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
   Pattern {
-    id: "new_function",
-    description: "new Function() constructor",
-    query: "(new_expression constructor: (identifier) @id (#eq? @id \"Function\")) @vuln",
+    id: Cow::Borrowed("new_function"),
+    description: Cow::Borrowed("new Function() constructor"),
+    query: Cow::Borrowed("(new_expression constructor: (identifier) @id (#eq? @id \"Function\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "document_write",
-    description: "document.write() call",
-    query: "(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"document\") property: (property_identifier) @prop (#eq? @prop \"write\"))) @vuln",
+    id: Cow::Borrowed("document_write"),
+    description: Cow::Borrowed("document.write() call"),
+    query: Cow::Borrowed("(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"document\") property: (property_identifier) @prop (#eq? @prop \"write\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "inner_html_assignment",
-    description: "Assignment to element.innerHTML",
-    query: "(assignment_expression left: (member_expression property: (property_identifier) @prop (#eq? @prop \"innerHTML\"))) @vuln",
+    id: Cow::Borrowed("inner_html_assignment"),
+    description: Cow::Borrowed("Assignment to element.innerHTML"),
+    query: Cow::Borrowed("(assignment_expression left: (member_expression property: (property_identifier) @prop (#eq? @prop \"innerHTML\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: Some(Fix {
+      description: Cow::Borrowed("use textContent instead of innerHTML to avoid HTML injection"),
+      template: Cow::Borrowed("$obj.textContent = $value"),
+    }),
   },
   Pattern {
-    id: "settimeout_string",
-    description: "setTimeout / setInterval with a string argument",
-    query: "(call_expression function: (identifier) @id (#match? @id \"setTimeout|setInterval\") arguments: (arguments (string) @code . _)) @vuln",
+    id: Cow::Borrowed("settimeout_string"),
+    description: Cow::Borrowed("setTimeout / setInterval with a string argument"),
+    query: Cow::Borrowed("(call_expression function: (identifier) @id (#match? @id \"setTimeout|setInterval\") arguments: (arguments (string) @code . _)) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "json_parse",
-    description: "JSON.parse on dynamic string",
-    query: "(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"JSON\") property: (property_identifier) @prop (#eq? @prop \"parse\"))) @vuln",
+    id: Cow::Borrowed("json_parse"),
+    description: Cow::Borrowed("JSON.parse on dynamic string"),
+    query: Cow::Borrowed("(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"JSON\") property: (property_identifier) @prop (#eq? @prop \"parse\"))) @vuln"),
     severity: Severity::Low,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "outer_html_assignment",
-    description: "Assignment to element.outerHTML",
-    query: "(assignment_expression
+    id: Cow::Borrowed("outer_html_assignment"),
+    description: Cow::Borrowed("Assignment to element.outerHTML"),
+    query: Cow::Borrowed("(assignment_expression
                left: (member_expression
                         property: (property_identifier) @prop
-                        (#eq? @prop \"outerHTML\"))) @vuln",
+                        (#eq? @prop \"outerHTML\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "insert_adjacent_html",
-    description: "insertAdjacentHTML() call",
-    query: "(call_expression
+    id: Cow::Borrowed("insert_adjacent_html"),
+    description: Cow::Borrowed("insertAdjacentHTML() call"),
+    query: Cow::Borrowed("(call_expression
                function: (member_expression
                            property: (property_identifier) @prop
-                           (#eq? @prop \"insertAdjacentHTML\"))) @vuln",
+                           (#eq? @prop \"insertAdjacentHTML\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "location_href_assignment",
-    description: "Assignment to window.location / location.href",
-    query: "(assignment_expression
+    id: Cow::Borrowed("location_href_assignment"),
+    description: Cow::Borrowed("Assignment to window.location / location.href"),
+    query: Cow::Borrowed("(assignment_expression
                left: (member_expression
                         object: (identifier)? @obj
                         property: (property_identifier) @prop
-                        (#match? @prop \"location|href\"))) @vuln",
+                        (#match? @prop \"location|href\"))) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "cookie_assignment",
-    description: "Write to document.cookie",
-    query: "(assignment_expression
+    id: Cow::Borrowed("cookie_assignment"),
+    description: Cow::Borrowed("Write to document.cookie"),
+    query: Cow::Borrowed("(assignment_expression
                left: (member_expression
                         object: (identifier) @obj
                         (#eq? @obj \"document\")
                         property: (property_identifier) @prop
-                        (#eq? @prop \"cookie\"))) @vuln",
+                        (#eq? @prop \"cookie\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "proto_pollution",
-    description: "Assignment to __proto__ (prototype pollution)",
-    query: "(assignment_expression
+    id: Cow::Borrowed("proto_pollution"),
+    description: Cow::Borrowed("Assignment to __proto__ (prototype pollution)"),
+    query: Cow::Borrowed("(assignment_expression
                left: (member_expression
                         property: (property_identifier) @prop
-                        (#eq? @prop \"__proto__\"))) @vuln",
+                        (#eq? @prop \"__proto__\"))) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "weak_hash_md5",
-    description: "crypto.createHash(\"md5\")",
-    query: "(call_expression
+    id: Cow::Borrowed("weak_hash_md5"),
+    description: Cow::Borrowed("crypto.createHash(\"md5\")"),
+    query: Cow::Borrowed("(call_expression
                function: (member_expression
                            object: (identifier) @obj
                            (#eq? @obj \"crypto\")
@@ -96,27 +133,39 @@ pub const PATTERNS: &[Pattern] = &[
                            (#eq? @prop \"createHash\"))
                arguments: (arguments
                             (string) @alg
-                            (#eq? @alg \"md5\"))) @vuln",
+                            (#eq? @alg \"md5\"))) @vuln"),
     severity: Severity::Low,
+    cwe: None,
+    owasp: None,
+    fix: Some(Fix {
+      description: Cow::Borrowed("use a collision-resistant hash instead of md5"),
+      template: Cow::Borrowed("$obj.createHash(\"sha256\")"),
+    }),
   },
   Pattern {
-    id: "regexp_constructor_string",
-    description: "new RegExp() with a dynamic string",
-    query: "(new_expression
+    id: Cow::Borrowed("regexp_constructor_string"),
+    description: Cow::Borrowed("new RegExp() with a dynamic string"),
+    query: Cow::Borrowed("(new_expression
                constructor: (identifier) @id
                (#eq? @id \"RegExp\")
-               arguments: (arguments (string) @pattern)) @vuln",
+               arguments: (arguments (string) @pattern)) @vuln"),
     severity: Severity::Low,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "dangerous_extend_builtin",
-    description: "Extending Object.prototype (may lead to collisions/pollution)",
-    query: "(assignment_expression
+    id: Cow::Borrowed("dangerous_extend_builtin"),
+    description: Cow::Borrowed("Extending Object.prototype (may lead to collisions/pollution)"),
+    query: Cow::Borrowed("(assignment_expression
                left: (member_expression
                         object: (identifier) @obj
                         (#eq? @obj \"Object\")
                         property: (property_identifier) @prop
-                        (#eq? @prop \"prototype\"))) @vuln",
+                        (#eq? @prop \"prototype\"))) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
 ];
@@ -12,14 +12,16 @@ pub mod typescript;
 use console::style;
 use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Default)]
 pub enum Severity {
     High,
     Medium,
+    #[default]
     Low,
 }
 
@@ -58,17 +60,43 @@ impl FromStr for Severity {
     }
 }
 
+/// A suggested rewrite for a matched `Pattern`.
+///
+/// `template` is expressed in terms of the query's own capture names (e.g.
+/// `@obj`, `@prop`) using `$name` placeholders, similar to how editor
+/// quick-fixes rewrite matched nodes without needing a full AST rebuild.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Fix {
+    /// Short human-readable summary, e.g. "replace innerHTML with textContent".
+    pub description: Cow<'static, str>,
+    /// Rewrite template over the query's capture names.
+    pub template: Cow<'static, str>,
+}
+
 /// One AST pattern with a tree-sitter query and meta-data.
+///
+/// Fields are `Cow<'static, str>` rather than `&'static str` so that
+/// built-in patterns (defined as `const` slices of string literals) and
+/// user-supplied rules (parsed at runtime from config, so necessarily
+/// owned `String`s) can share one type without leaking memory to fake a
+/// `'static` lifetime for the latter.
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct Pattern {
     /// Unique identifier (snake-case preferred).
-    pub id: &'static str,
+    pub id: Cow<'static, str>,
     /// Human-readable explanation.
-    pub description: &'static str,
+    pub description: Cow<'static, str>,
     /// tree-sitter query string.
-    pub query: &'static str,
+    pub query: Cow<'static, str>,
     /// Rough severity bucket.
     pub severity: Severity,
+    /// CWE identifier this rule maps to (e.g. `"CWE-78"`), when one applies.
+    pub cwe: Option<&'static str>,
+    /// OWASP Top 10 category this rule maps to (e.g. `"A03:2021-Injection"`),
+    /// when one applies.
+    pub owasp: Option<&'static str>,
+    /// Suggested autofix, if one is known for this rule.
+    pub fix: Option<Fix>,
 }
 
 /// Global, lazily-initialised registry: lang-name → pattern slice
@@ -114,6 +142,89 @@ pub fn load(lang: &str) -> Vec<Pattern> {
     REGISTRY.get(key.as_str()).copied().unwrap_or(&[]).to_vec()
 }
 
+/// Look up a rule's `description`/`severity` by id, searching every
+/// language's table. Used at output time so formatters (SARIF, …) can
+/// surface human-readable rule metadata from just a `Diag::id`.
+///
+/// IDs are unique by convention but not enforced across languages, so this
+/// returns the first match.
+pub fn describe(id: &str) -> Option<&'static Pattern> {
+    REGISTRY
+        .values()
+        .flat_map(|patterns| patterns.iter())
+        .find(|p| p.id.as_ref() == id)
+}
+
+/// All built-in rule ids whose `cwe` metadata matches `cwe` exactly (e.g.
+/// `"CWE-78"`). The `issues` table has no CWE column of its own, so the
+/// `cwe:`/`owasp:` query-language filters (see `utils::query_lang`) resolve
+/// a taxonomy id back to its member rule ids here rather than in SQL.
+pub fn rule_ids_for_cwe(cwe: &str) -> Vec<&'static str> {
+    REGISTRY
+        .values()
+        .flat_map(|patterns| patterns.iter())
+        .filter(|p| p.cwe == Some(cwe))
+        .map(|p| p.id.as_ref())
+        .collect()
+}
+
+/// Same as [`rule_ids_for_cwe`], keyed on OWASP category instead.
+pub fn rule_ids_for_owasp(owasp: &str) -> Vec<&'static str> {
+    REGISTRY
+        .values()
+        .flat_map(|patterns| patterns.iter())
+        .filter(|p| p.owasp == Some(owasp))
+        .map(|p| p.id.as_ref())
+        .collect()
+}
+
+/// Compile a user-supplied rule's query against its target language's
+/// grammar, rejecting it up front rather than letting it fail silently
+/// later inside [`crate::utils::query_cache::for_lang`].
+///
+/// Called from `Config::load` for every rule sourced from `nyx.local` or a
+/// dropped-in rule file, so a typo'd query is a startup error the author
+/// sees immediately instead of a rule that quietly never matches anything.
+pub fn validate_user_rule(rule: &crate::utils::config::UserRule) -> crate::errors::NyxResult<()> {
+    let Some(lang) = crate::ast::language_for_slug(&rule.lang.to_ascii_lowercase()) else {
+        return Err(crate::errors::NyxError::InvalidRule(format!(
+            "rule '{}': unknown language '{}'",
+            rule.id, rule.lang
+        )));
+    };
+
+    tree_sitter::Query::new(&lang, &rule.query).map_err(|e| {
+        crate::errors::NyxError::InvalidRule(format!(
+            "rule '{}' ({}): invalid tree-sitter query: {e}\n  at: {}",
+            rule.id,
+            rule.lang,
+            query_error_context(&rule.query, e.offset)
+        ))
+    })?;
+
+    Ok(())
+}
+
+/// Pull a short window of `query` around a tree-sitter `QueryError`'s byte
+/// `offset` so the error names the offending substring, not just a row/col
+/// the user has to go count out themselves.
+fn query_error_context(query: &str, offset: usize) -> &str {
+    const WINDOW: usize = 30;
+    let offset = offset.min(query.len());
+    let start = query[..offset]
+        .char_indices()
+        .rev()
+        .nth(WINDOW)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let end = query[offset..]
+        .char_indices()
+        .nth(WINDOW)
+        .map(|(i, _)| offset + i)
+        .unwrap_or(query.len());
+    query[start..end].trim()
+}
+
 #[test]
 fn severity_as_db_str_roundtrip() {
     for &s in &[Severity::High, Severity::Medium, Severity::Low] {
@@ -1,40 +1,59 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
   Pattern {
-    id: "eval_call",
-    description: "eval($code) execution",
-    query: "(function_call_expression function: (name) @n (#eq? @n \"eval\")) @vuln",
+    id: Cow::Borrowed("eval_call"),
+    description: Cow::Borrowed("eval($code) execution"),
+    query: Cow::Borrowed("(function_call_expression function: (name) @n (#eq? @n \"eval\")) @vuln"),
     severity: Severity::High,
+    cwe: Some("CWE-95"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
   Pattern {
-    id: "preg_replace_e",
-    description: "preg_replace with deprecated /e modifier",
-    query: "(function_call_expression function: (name) @n (#eq? @n \"preg_replace\") arguments: (arguments (string) @pat (#match? @pat \"/.*e.*$/\"))) @vuln",
+    id: Cow::Borrowed("preg_replace_e"),
+    description: Cow::Borrowed("preg_replace with deprecated /e modifier"),
+    query: Cow::Borrowed("(function_call_expression function: (name) @n (#eq? @n \"preg_replace\") arguments: (arguments (string) @pat (#match? @pat \"/.*e.*$/\"))) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "create_function",
-    description: "create_function(...) anonymous eval-like",
-    query: "(function_call_expression function: (name) @n (#eq? @n \"create_function\")) @vuln",
+    id: Cow::Borrowed("create_function"),
+    description: Cow::Borrowed("create_function(...) anonymous eval-like"),
+    query: Cow::Borrowed("(function_call_expression function: (name) @n (#eq? @n \"create_function\")) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "unserialize_call",
-    description: "unserialize(...) on user input",
-    query: "(function_call_expression function: (name) @n (#eq? @n \"unserialize\")) @vuln",
+    id: Cow::Borrowed("unserialize_call"),
+    description: Cow::Borrowed("unserialize(...) on user input"),
+    query: Cow::Borrowed("(function_call_expression function: (name) @n (#eq? @n \"unserialize\")) @vuln"),
     severity: Severity::High,
+    cwe: Some("CWE-502"),
+    owasp: Some("A08:2021-Software-and-Data-Integrity-Failures"),
+    fix: None,
   },
   Pattern {
-    id: "mysql_query_concat",
-    description: "mysql_query with concatenated SQL",
-    query: "(function_call_expression function: (name) @n (#eq? @n \"mysql_query\") arguments: (arguments (binary_expression) @concat)) @vuln",
+    id: Cow::Borrowed("mysql_query_concat"),
+    description: Cow::Borrowed("mysql_query with concatenated SQL"),
+    query: Cow::Borrowed("(function_call_expression function: (name) @n (#eq? @n \"mysql_query\") arguments: (arguments (binary_expression) @concat)) @vuln"),
     severity: Severity::Medium,
+    cwe: Some("CWE-89"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
   Pattern {
-    id: "system_call",
-    description: "system()/shell_exec()/exec() command execution",
-    query: "(function_call_expression function: (name) @n (#match? @n \"system|shell_exec|exec|passthru\")) @vuln",
+    id: Cow::Borrowed("system_call"),
+    description: Cow::Borrowed("system()/shell_exec()/exec() command execution"),
+    query: Cow::Borrowed("(function_call_expression function: (name) @n (#match? @n \"system|shell_exec|exec|passthru\")) @vuln"),
     severity: Severity::Medium,
+    cwe: Some("CWE-78"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
 ];
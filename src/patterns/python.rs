@@ -1,22 +1,41 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
   Pattern {
-    id: "eval_call",
-    description: "eval() on dynamic input",
-    query: "(call function: (identifier) @id (#eq? @id \"eval\")) @vuln",
+    id: Cow::Borrowed("eval_call"),
+    description: Cow::Borrowed("eval() on dynamic input"),
+    query: Cow::Borrowed("(call function: (identifier) @id (#eq? @id \"eval\")) @vuln"),
     severity: Severity::High,
+    cwe: Some("CWE-95"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   },
   Pattern {
-    id: "exec_call",
-    description: "exec(...) execution of dynamic code",
-    query: "(call function: (identifier) @id (#eq? @id \"exec\")) @vuln",
+    id: Cow::Borrowed("exec_call"),
+    description: Cow::Borrowed("exec(...) execution of dynamic code"),
+    query: Cow::Borrowed("(call function: (identifier) @id (#eq? @id \"exec\")) @vuln"),
     severity: Severity::High,
+    cwe: None,
+    owasp: None,
+    fix: None,
   },
   Pattern {
-    id: "subprocess_shell_true",
-    description: "subprocess.* with shell=True",
-    query: "(call function: (attribute object: (identifier) @pkg (#eq? @pkg \"subprocess\")) arguments: (argument_list . (keyword_argument name: (identifier) @k (#eq? @k \"shell\")) (true) @val)) @vuln",
+    id: Cow::Borrowed("subprocess_shell_true"),
+    description: Cow::Borrowed("subprocess.* with shell=True"),
+    query: Cow::Borrowed("(call function: (attribute object: (identifier) @pkg (#eq? @pkg \"subprocess\")) arguments: (argument_list . (keyword_argument name: (identifier) @k (#eq? @k \"shell\")) (true) @val)) @vuln"),
     severity: Severity::Medium,
+    cwe: None,
+    owasp: None,
+    fix: None,
+  },
+  Pattern {
+    id: Cow::Borrowed("cursor_execute_unsafe_format"),
+    description: Cow::Borrowed("cursor.execute(...) with a %-formatted or f-string SQL query"),
+    query: Cow::Borrowed("(call function: (attribute attribute: (identifier) @m (#eq? @m \"execute\")) arguments: (argument_list . [(binary_expression) (string (interpolation))] @tainted)) @vuln"),
+    severity: Severity::High,
+    cwe: Some("CWE-89"),
+    owasp: Some("A03:2021-Injection"),
+    fix: None,
   }
 ];
@@ -1,9 +1,10 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 pub const PATTERNS: &[Pattern] = &[
     // ---------- Runtime code-execution primitives ----------
     Pattern {
-        id: "eval_call",
-        description: "Kernel#eval usage",
+        id: Cow::Borrowed("eval_call"),
+        description: Cow::Borrowed("Kernel#eval usage"),
         query: r#"
           (call
             (identifier) @id
@@ -11,10 +12,13 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::High,
+        cwe: Some("CWE-95"),
+        owasp: Some("A03:2021-Injection"),
+        fix: None,
     },
     Pattern {
-        id: "instance_eval_call",
-        description: "Object#instance_eval usage",
+        id: Cow::Borrowed("instance_eval_call"),
+        description: Cow::Borrowed("Object#instance_eval usage"),
         query: r#"
           (call
             (identifier) @id
@@ -22,10 +26,13 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "class_eval_call",
-        description: "Module#class_eval / module_eval usage",
+        id: Cow::Borrowed("class_eval_call"),
+        description: Cow::Borrowed("Module#class_eval / module_eval usage"),
         query: r#"
           (call
             (identifier) @id
@@ -33,11 +40,14 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     // ---------- Shell execution ----------
     Pattern {
-        id: "system_exec_interp",
-        description: "system/exec with string interpolation",
+        id: Cow::Borrowed("system_exec_interp"),
+        description: Cow::Borrowed("system/exec with string interpolation"),
         query: r#"
           (call
             method: (identifier) @m
@@ -50,18 +60,24 @@ pub const PATTERNS: &[Pattern] = &[
           )
         "#,
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "backtick_command",
-        description: "Back-tick shell execution",
+        id: Cow::Borrowed("backtick_command"),
+        description: Cow::Borrowed("Back-tick shell execution"),
         // `uname -a`
         query: r#"(shell_command) @vuln"#,
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     // ---------- Dangerous deserialisation ----------
     Pattern {
-        id: "yaml_load",
-        description: "YAML.load / Psych.load (arbitrary object deserialisation)",
+        id: Cow::Borrowed("yaml_load"),
+        description: Cow::Borrowed("YAML.load / Psych.load (arbitrary object deserialisation)"),
         query: r#"
           (call
             receiver: (constant) @recv
@@ -71,10 +87,13 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::High,
+        cwe: Some("CWE-502"),
+        owasp: Some("A08:2021-Software-and-Data-Integrity-Failures"),
+        fix: None,
     },
     Pattern {
-        id: "marshal_load",
-        description: "Marshal.load usage",
+        id: Cow::Borrowed("marshal_load"),
+        description: Cow::Borrowed("Marshal.load usage"),
         query: r#"
           (call
             receiver: (constant) @recv
@@ -84,11 +103,14 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::High,
+        cwe: Some("CWE-502"),
+        owasp: Some("A08:2021-Software-and-Data-Integrity-Failures"),
+        fix: None,
     },
     // ---------- Reflection / meta-programming ----------
     Pattern {
-        id: "send_dynamic",
-        description: "send() with dynamic first argument (not a literal symbol)",
+        id: Cow::Borrowed("send_dynamic"),
+        description: Cow::Borrowed("send() with dynamic first argument (not a literal symbol)"),
         query: r#"
           (call
             method: (identifier) @m
@@ -102,10 +124,13 @@ pub const PATTERNS: &[Pattern] = &[
           )
         "#,
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "constantize_call",
-        description: "ActiveSupport constantize / safe_constantize on tainted data",
+        id: Cow::Borrowed("constantize_call"),
+        description: Cow::Borrowed("ActiveSupport constantize / safe_constantize on tainted data"),
         query: r#"
           (call
             method: (identifier) @m
@@ -113,11 +138,14 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     // ---------- Insecure resource access ----------
     Pattern {
-        id: "open_uri_http",
-        description: "Kernel#open with HTTP(S) URL (open-uri auto-follow)",
+        id: Cow::Borrowed("open_uri_http"),
+        description: Cow::Borrowed("Kernel#open with HTTP(S) URL (open-uri auto-follow)"),
         query: r#"
           (call
             method: (identifier) @m
@@ -129,5 +157,8 @@ pub const PATTERNS: &[Pattern] = &[
           ) @vuln
         "#,
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
 ];
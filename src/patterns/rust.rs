@@ -1,118 +1,164 @@
 use crate::patterns::{Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
     Pattern {
-        id: "unsafe_block",
-        description: "Use of an `unsafe` block",
-        query: "(unsafe_block) @vuln",
+        id: Cow::Borrowed("unsafe_block"),
+        description: Cow::Borrowed("Use of an `unsafe` block"),
+        query: Cow::Borrowed("(unsafe_block) @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "unsafe_fn",
-        description: "`unsafe fn` declaration",
-        query: "(function_item
+        id: Cow::Borrowed("unsafe_fn"),
+        description: Cow::Borrowed("`unsafe fn` declaration"),
+        query: Cow::Borrowed("(function_item
                (function_modifiers) @mods
-               (#match? @mods \"^unsafe\\b\")) @vuln",
+               (#match? @mods \"^unsafe\\b\")) @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "transmute_call",
-        description: "`std::mem::transmute` call",
-        query: "(call_expression
+        id: Cow::Borrowed("transmute_call"),
+        description: Cow::Borrowed("`std::mem::transmute` call"),
+        query: Cow::Borrowed("(call_expression
                   function: (scoped_identifier
                               path: (identifier) @p (#eq? @p \"mem\")
                               name: (identifier) @f (#eq? @f \"transmute\")))
-                @vuln",
+                @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "copy_nonoverlapping",
-        description: "Raw pointer `copy_nonoverlapping`",
-        query: "(call_expression
+        id: Cow::Borrowed("copy_nonoverlapping"),
+        description: Cow::Borrowed("Raw pointer `copy_nonoverlapping`"),
+        query: Cow::Borrowed("(call_expression
                   function: (scoped_identifier
                               path: (identifier) @p (#eq? @p \"ptr\")
                               name: (identifier) @f (#eq? @f \"copy_nonoverlapping\")))
-                @vuln",
+                @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "get_unchecked",
-        description: "`get_unchecked` / `get_unchecked_mut` slice access",
-        query: "(call_expression
+        id: Cow::Borrowed("get_unchecked"),
+        description: Cow::Borrowed("`get_unchecked` / `get_unchecked_mut` slice access"),
+        query: Cow::Borrowed("(call_expression
                   function: (field_expression
                               field: (field_identifier) @m
-                              (#match? @m \"get_unchecked(_mut)?\"))) @vuln",
+                              (#match? @m \"get_unchecked(_mut)?\"))) @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "unwrap_call",
-        description: "`.unwrap()` call (may panic)",
-        query: "(call_expression
+        id: Cow::Borrowed("unwrap_call"),
+        description: Cow::Borrowed("`.unwrap()` call (may panic)"),
+        query: Cow::Borrowed("(call_expression
               function: (field_expression
                           field: (field_identifier) @name
                           (#eq? @name \"unwrap\")))   ; exact match
-            @vuln",
+            @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "expect_call",
-        description: "`.expect()` call (may panic)",
-        query: "(call_expression
+        id: Cow::Borrowed("expect_call"),
+        description: Cow::Borrowed("`.expect()` call (may panic)"),
+        query: Cow::Borrowed("(call_expression
                   function: (field_expression
                               field: (field_identifier) @name
-                              (#eq? @name \"expect\"))) @vuln",
+                              (#eq? @name \"expect\"))) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "panic_macro",
-        description: "`panic!` macro invocation",
-        query: "(macro_invocation (identifier) @id (#eq? @id \"panic\")) @vuln",
+        id: Cow::Borrowed("panic_macro"),
+        description: Cow::Borrowed("`panic!` macro invocation"),
+        query: Cow::Borrowed("(macro_invocation (identifier) @id (#eq? @id \"panic\")) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "todo_or_unimplemented",
-        description: "`todo!()` / `unimplemented!()` placeholder",
-        query: "(macro_invocation
+        id: Cow::Borrowed("todo_or_unimplemented"),
+        description: Cow::Borrowed("`todo!()` / `unimplemented!()` placeholder"),
+        query: Cow::Borrowed("(macro_invocation
                   (identifier) @id
-                  (#match? @id \"todo|unimplemented\")) @vuln",
+                  (#match? @id \"todo|unimplemented\")) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "narrow_cast_with_as",
-        description: "`as` cast to an 8-/16-bit integer (possible truncation)",
-        query: "(type_cast_expression
+        id: Cow::Borrowed("narrow_cast_with_as"),
+        description: Cow::Borrowed("`as` cast to an 8-/16-bit integer (possible truncation)"),
+        query: Cow::Borrowed("(type_cast_expression
                   type: (primitive_type) @to
-                  (#match? @to \"^u?i(8|16)$\")) @vuln",
+                  (#match? @to \"^u?i(8|16)$\")) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "mem_zeroed",
-        description: "`std::mem::zeroed()`",
-        query: "(call_expression function:(scoped_identifier path:(identifier)@p (#eq? @p \"mem\") name:(identifier)@n (#eq? @n \"zeroed\")))@vuln",
+        id: Cow::Borrowed("mem_zeroed"),
+        description: Cow::Borrowed("`std::mem::zeroed()`"),
+        query: Cow::Borrowed("(call_expression function:(scoped_identifier path:(identifier)@p (#eq? @p \"mem\") name:(identifier)@n (#eq? @n \"zeroed\")))@vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "mem_forget",
-        description: "`std::mem::forget()`",
-        query: "(call_expression function:(scoped_identifier path:(identifier)@p (#eq? @p \"mem\") name:(identifier)@n (#eq? @n \"forget\")))@vuln",
+        id: Cow::Borrowed("mem_forget"),
+        description: Cow::Borrowed("`std::mem::forget()`"),
+        query: Cow::Borrowed("(call_expression function:(scoped_identifier path:(identifier)@p (#eq? @p \"mem\") name:(identifier)@n (#eq? @n \"forget\")))@vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "ptr_read",
-        description: "`ptr::read_*` raw-ptr read",
-        query: "(call_expression function:(scoped_identifier path:(identifier)@p (#eq? @p \"ptr\") name:(identifier)@n (#match? @n \"read(_volatile)?\")))@vuln",
+        id: Cow::Borrowed("ptr_read"),
+        description: Cow::Borrowed("`ptr::read_*` raw-ptr read"),
+        query: Cow::Borrowed("(call_expression function:(scoped_identifier path:(identifier)@p (#eq? @p \"ptr\") name:(identifier)@n (#match? @n \"read(_volatile)?\")))@vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "arc_unwrap",
-        description: "`Arc::unwrap_or_else_unchecked`",
-        query: "(call_expression function:(scoped_identifier name:(identifier)@n (#eq? @n \"unwrap_or_else_unchecked\")))@vuln",
+        id: Cow::Borrowed("arc_unwrap"),
+        description: Cow::Borrowed("`Arc::unwrap_or_else_unchecked`"),
+        query: Cow::Borrowed("(call_expression function:(scoped_identifier name:(identifier)@n (#eq? @n \"unwrap_or_else_unchecked\")))@vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "dbg_macro",
-        description: "`dbg!()` left in code",
-        query: "(macro_invocation (identifier)@id (#eq? @id \"dbg\"))@vuln",
+        id: Cow::Borrowed("dbg_macro"),
+        description: Cow::Borrowed("`dbg!()` left in code"),
+        query: Cow::Borrowed("(macro_invocation (identifier)@id (#eq? @id \"dbg\"))@vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
 ];
@@ -1,106 +1,164 @@
-use crate::patterns::{Pattern, Severity};
+use crate::patterns::{Fix, Pattern, Severity};
+use std::borrow::Cow;
 
 pub const PATTERNS: &[Pattern] = &[
     Pattern {
-        id: "eval_call",
-        description: "Use of eval()",
-        query: "(call_expression function: (identifier) @id (#eq? @id \"eval\")) @vuln",
+        id: Cow::Borrowed("eval_call"),
+        description: Cow::Borrowed("Use of eval()"),
+        query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"eval\")) @vuln"),
         severity: Severity::High,
+        cwe: Some("CWE-95"),
+        owasp: Some("A03:2021-Injection"),
+        fix: None,
     },
     Pattern {
-        id: "new_function",
-        description: "new Function() constructor",
-        query: "(new_expression constructor: (identifier) @id (#eq? @id \"Function\")) @vuln",
+        id: Cow::Borrowed("new_function"),
+        description: Cow::Borrowed("new Function() constructor"),
+        query: Cow::Borrowed("(new_expression constructor: (identifier) @id (#eq? @id \"Function\")) @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "document_write",
-        description: "document.write() call",
-        query: "(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"document\") property: (property_identifier) @prop (#eq? @prop \"write\"))) @vuln",
+        id: Cow::Borrowed("document_write"),
+        description: Cow::Borrowed("document.write() call"),
+        query: Cow::Borrowed("(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"document\") property: (property_identifier) @prop (#eq? @prop \"write\"))) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "inner_html_assignment",
-        description: "Assignment to element.innerHTML",
-        query: "(assignment_expression left: (member_expression property: (property_identifier) @prop (#eq? @prop \"innerHTML\"))) @vuln",
+        id: Cow::Borrowed("inner_html_assignment"),
+        description: Cow::Borrowed("Assignment to element.innerHTML"),
+        query: Cow::Borrowed("(assignment_expression left: (member_expression property: (property_identifier) @prop (#eq? @prop \"innerHTML\"))) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: Some(Fix {
+            description: Cow::Borrowed("use textContent instead of innerHTML to avoid HTML injection"),
+            template: Cow::Borrowed("$obj.textContent = $value"),
+        }),
     },
     Pattern {
-        id: "settimeout_string",
-        description: "setTimeout / setInterval with a string argument",
-        query: "(call_expression function: (identifier) @id (#match? @id \"setTimeout|setInterval\") arguments: (arguments (string) @code . _)) @vuln",
+        id: Cow::Borrowed("settimeout_string"),
+        description: Cow::Borrowed("setTimeout / setInterval with a string argument"),
+        query: Cow::Borrowed("(call_expression function: (identifier) @id (#match? @id \"setTimeout|setInterval\") arguments: (arguments (string) @code . _)) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "any_type",
-        description: "Type annotation of `any`",
-        query: "(type_annotation (predefined_type) @t (#eq? @t \"any\")) @vuln",
+        id: Cow::Borrowed("any_type"),
+        description: Cow::Borrowed("Type annotation of `any`"),
+        query: Cow::Borrowed("(type_annotation (predefined_type) @t (#eq? @t \"any\")) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "json_parse",
-        description: "JSON.parse on dynamic string",
-        query: "(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"JSON\") property: (property_identifier) @prop (#eq? @prop \"parse\"))) @vuln",
+        id: Cow::Borrowed("json_parse"),
+        description: Cow::Borrowed("JSON.parse on dynamic string"),
+        query: Cow::Borrowed("(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"JSON\") property: (property_identifier) @prop (#eq? @prop \"parse\"))) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "as_any_assertion",
-        description: "Type assertion to `any` using `as any`",
-        query: "(as_expression type: (predefined_type) @t (#eq? @t \"any\")) @vuln",
+        id: Cow::Borrowed("as_any_assertion"),
+        description: Cow::Borrowed("Type assertion to `any` using `as any`"),
+        query: Cow::Borrowed("(as_expression type: (predefined_type) @t (#eq? @t \"any\")) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "type_assertion_any",
-        description: "Type assertion to `any` using `<any>` syntax",
-        query: "(type_assertion type: (predefined_type) @t (#eq? @t \"any\")) @vuln",
+        id: Cow::Borrowed("type_assertion_any"),
+        description: Cow::Borrowed("Type assertion to `any` using `<any>` syntax"),
+        query: Cow::Borrowed("(type_assertion type: (predefined_type) @t (#eq? @t \"any\")) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "outer_html_assignment",
-        description: "Assignment to element.outerHTML",
-        query: "(assignment_expression left: (member_expression property: (property_identifier) @prop (#eq? @prop \"outerHTML\"))) @vuln",
+        id: Cow::Borrowed("outer_html_assignment"),
+        description: Cow::Borrowed("Assignment to element.outerHTML"),
+        query: Cow::Borrowed("(assignment_expression left: (member_expression property: (property_identifier) @prop (#eq? @prop \"outerHTML\"))) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "insert_adjacent_html",
-        description: "insertAdjacentHTML() call",
-        query: "(call_expression function: (member_expression property: (property_identifier) @prop (#eq? @prop \"insertAdjacentHTML\"))) @vuln",
+        id: Cow::Borrowed("insert_adjacent_html"),
+        description: Cow::Borrowed("insertAdjacentHTML() call"),
+        query: Cow::Borrowed("(call_expression function: (member_expression property: (property_identifier) @prop (#eq? @prop \"insertAdjacentHTML\"))) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "document_cookie_write",
-        description: "Write to document.cookie",
-        query: "(assignment_expression left: (member_expression object: (identifier) @obj (#eq? @obj \"document\") property: (property_identifier) @prop (#eq? @prop \"cookie\"))) @vuln",
+        id: Cow::Borrowed("document_cookie_write"),
+        description: Cow::Borrowed("Write to document.cookie"),
+        query: Cow::Borrowed("(assignment_expression left: (member_expression object: (identifier) @obj (#eq? @obj \"document\") property: (property_identifier) @prop (#eq? @prop \"cookie\"))) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "onclick_setattribute",
-        description: "Element.setAttribute('onclick', …)",
-        query: "(call_expression function: (member_expression property: (property_identifier) @prop (#eq? @prop \"setAttribute\")) arguments: (arguments (string) @name (#eq? @name \"\\\"onclick\\\"\") . (string) @handler)) @vuln",
+        id: Cow::Borrowed("onclick_setattribute"),
+        description: Cow::Borrowed("Element.setAttribute('onclick', …)"),
+        query: Cow::Borrowed("(call_expression function: (member_expression property: (property_identifier) @prop (#eq? @prop \"setAttribute\")) arguments: (arguments (string) @name (#eq? @name \"\\\"onclick\\\"\") . (string) @handler)) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "math_random_call",
-        description: "Use of Math.random() for security-sensitive randomness",
-        query: "(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"Math\") property: (property_identifier) @prop (#eq? @prop \"random\"))) @vuln",
+        id: Cow::Borrowed("math_random_call"),
+        description: Cow::Borrowed("Use of Math.random() for security-sensitive randomness"),
+        query: Cow::Borrowed("(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"Math\") property: (property_identifier) @prop (#eq? @prop \"random\"))) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "crypto_createhash_md5",
-        description: "Insecure hash algorithm: crypto.createHash('md5')",
-        query: "(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"crypto\") property: (property_identifier) @prop (#eq? @prop \"createHash\")) arguments: (arguments (string) @alg (#match? @alg \"(?i)\\\"md5\\\"\"))) @vuln",
+        id: Cow::Borrowed("crypto_createhash_md5"),
+        description: Cow::Borrowed("Insecure hash algorithm: crypto.createHash('md5')"),
+        query: Cow::Borrowed("(call_expression function: (member_expression object: (identifier) @obj (#eq? @obj \"crypto\") property: (property_identifier) @prop (#eq? @prop \"createHash\")) arguments: (arguments (string) @alg (#match? @alg \"(?i)\\\"md5\\\"\"))) @vuln"),
         severity: Severity::Medium,
+        cwe: None,
+        owasp: None,
+        fix: Some(Fix {
+            description: Cow::Borrowed("use a collision-resistant hash instead of md5"),
+            template: Cow::Borrowed("$obj.createHash(\"sha256\")"),
+        }),
     },
     Pattern {
-        id: "fetch_http_url",
-        description: "fetch() over plain HTTP",
-        query: "(call_expression function: (identifier) @id (#eq? @id \"fetch\") arguments: (arguments (string) @url (#match? @url \"^\\\"http://\"))) @vuln",
+        id: Cow::Borrowed("fetch_http_url"),
+        description: Cow::Borrowed("fetch() over plain HTTP"),
+        query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"fetch\") arguments: (arguments (string) @url (#match? @url \"^\\\"http://\"))) @vuln"),
         severity: Severity::Low,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
     Pattern {
-        id: "xhr_eval_response",
-        description: "eval() of XMLHttpRequest.responseText",
-        query: "(call_expression function: (identifier) @id (#eq? @id \"eval\") arguments: (arguments (member_expression property: (property_identifier) @prop (#eq? @prop \"responseText\")))) @vuln",
+        id: Cow::Borrowed("xhr_eval_response"),
+        description: Cow::Borrowed("eval() of XMLHttpRequest.responseText"),
+        query: Cow::Borrowed("(call_expression function: (identifier) @id (#eq? @id \"eval\") arguments: (arguments (member_expression property: (property_identifier) @prop (#eq? @prop \"responseText\")))) @vuln"),
         severity: Severity::High,
+        cwe: None,
+        owasp: None,
+        fix: None,
     },
 ];
@@ -0,0 +1,199 @@
+use crate::cfg::Cfg;
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeReferences};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A flat, row-major bit matrix over `n` dense indices, packed into `u64`
+/// words so an O(V²) closure costs O(V²/64) words instead of O(V²) `bool`s.
+#[derive(Debug, Clone)]
+struct BitMatrix {
+    n: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl BitMatrix {
+    fn new(n: usize) -> Self {
+        let words_per_row = n.div_ceil(64).max(1);
+        BitMatrix {
+            n,
+            words_per_row,
+            bits: vec![0u64; words_per_row * n.max(1)],
+        }
+    }
+
+    fn get(&self, i: usize, j: usize) -> bool {
+        let word = i * self.words_per_row + j / 64;
+        (self.bits[word] >> (j % 64)) & 1 == 1
+    }
+
+    fn set(&mut self, i: usize, j: usize) {
+        let word = i * self.words_per_row + j / 64;
+        self.bits[word] |= 1 << (j % 64);
+    }
+
+    /// ORs row `src` into row `dst`; returns whether `dst`'s row changed, so
+    /// the fixpoint loop in `TransitiveTaint::build` knows when to stop.
+    fn or_row_into(&mut self, dst: usize, src: usize) -> bool {
+        let mut changed = false;
+        for w in 0..self.words_per_row {
+            let s = self.bits[src * self.words_per_row + w];
+            let d = &mut self.bits[dst * self.words_per_row + w];
+            let merged = *d | s;
+            if merged != *d {
+                changed = true;
+                *d = merged;
+            }
+        }
+        changed
+    }
+
+    fn iter_set(&self, row: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.n).filter(move |&j| self.get(row, j))
+    }
+}
+
+/// The transitive-reachability closure of a [`Cfg`], precomputed once so
+/// `reaches` queries afterward are O(1) and `reachable_from` a single row scan
+/// instead of re-walking the graph every time. Immutable once built.
+pub struct TransitiveTaint {
+    index_of: HashMap<NodeIndex, usize>,
+    node_of: Vec<NodeIndex>,
+    closure: BitMatrix,
+}
+
+impl TransitiveTaint {
+    /// Assigns every node a dense index, seeds each row with its direct
+    /// successors, then iterates to a fixpoint ORing `row[j]` into `row[i]`
+    /// for every edge `i -> j` until no row changes — the standard
+    /// adjacency-closure fixpoint, just over node reachability rather than
+    /// `Cap` bits.
+    pub fn build(g: &Cfg) -> Self {
+        let node_of: Vec<NodeIndex> = g.node_references().map(|(idx, _)| idx).collect();
+        let index_of: HashMap<NodeIndex, usize> = node_of
+            .iter()
+            .enumerate()
+            .map(|(i, &idx)| (idx, i))
+            .collect();
+        let n = node_of.len();
+
+        let edges: HashSet<(usize, usize)> = g
+            .edge_references()
+            .map(|e| (index_of[&e.source()], index_of[&e.target()]))
+            .collect();
+
+        let mut closure = BitMatrix::new(n);
+        for &(i, j) in &edges {
+            closure.set(i, j);
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &(i, j) in &edges {
+                if closure.or_row_into(i, j) {
+                    changed = true;
+                }
+            }
+        }
+
+        TransitiveTaint {
+            index_of,
+            node_of,
+            closure,
+        }
+    }
+
+    /// Whether `b` is reachable from `a` (a node trivially reaches itself).
+    pub fn reaches(&self, a: NodeIndex, b: NodeIndex) -> bool {
+        match (self.index_of.get(&a), self.index_of.get(&b)) {
+            (Some(&i), Some(&j)) => i == j || self.closure.get(i, j),
+            _ => false,
+        }
+    }
+
+    /// Every node reachable from `a`, not including `a` itself.
+    pub fn reachable_from(&self, a: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        let i = self.index_of.get(&a).copied();
+        i.into_iter()
+            .flat_map(move |i| {
+                self.closure
+                    .iter_set(i)
+                    .filter(move |&j| j != i)
+                    .map(|j| self.node_of[j])
+            })
+    }
+
+    /// Reconstructs one witnessing `a -> .. -> b` path by BFS over `g`'s
+    /// original edges — the closure only tells us a path exists, not which
+    /// one, so this walks the real graph rather than the bit matrix.
+    pub fn minimal_path(&self, g: &Cfg, a: NodeIndex, b: NodeIndex) -> Option<Vec<NodeIndex>> {
+        if a == b {
+            return Some(vec![a]);
+        }
+
+        let mut pred: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut seen: HashSet<NodeIndex> = HashSet::new();
+        let mut q = VecDeque::new();
+        q.push_back(a);
+        seen.insert(a);
+
+        while let Some(n) = q.pop_front() {
+            for succ in g.neighbors(n) {
+                if seen.insert(succ) {
+                    pred.insert(succ, n);
+                    if succ == b {
+                        let mut path = vec![b];
+                        let mut cur = b;
+                        while let Some(&p) = pred.get(&cur) {
+                            path.push(p);
+                            cur = p;
+                        }
+                        path.reverse();
+                        return Some(path);
+                    }
+                    q.push_back(succ);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[test]
+fn reaches_across_multiple_hops() {
+    use crate::cfg::{EdgeKind, NodeInfo, StmtKind};
+
+    let mut g = Cfg::new();
+    let mk = |kind| NodeInfo {
+        kind,
+        span: (0, 0),
+        label: None,
+        defines: None,
+        uses: Vec::new(),
+        callee: None,
+        call_args: Vec::new(),
+    };
+
+    let a = g.add_node(mk(StmtKind::Entry));
+    let b = g.add_node(mk(StmtKind::Seq));
+    let c = g.add_node(mk(StmtKind::Exit));
+    let isolated = g.add_node(mk(StmtKind::Seq));
+    g.add_edge(a, b, EdgeKind::Seq);
+    g.add_edge(b, c, EdgeKind::Seq);
+
+    let closure = TransitiveTaint::build(&g);
+
+    assert!(closure.reaches(a, c));
+    assert!(closure.reaches(a, b));
+    assert!(!closure.reaches(c, a));
+    assert!(!closure.reaches(a, isolated));
+
+    let mut from_a: Vec<_> = closure.reachable_from(a).collect();
+    from_a.sort_by_key(|n| n.index());
+    assert_eq!(from_a, vec![b, c]);
+
+    assert_eq!(closure.minimal_path(&g, a, c), Some(vec![a, b, c]));
+    assert_eq!(closure.minimal_path(&g, a, isolated), None);
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// A cached, language-agnostic synopsis of one function's shape, persisted
+/// alongside the issue index so interprocedural passes can reuse it without
+/// re-parsing the file it came from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuncSummary {
+    pub name: String,
+}
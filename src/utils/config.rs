@@ -1,4 +1,4 @@
-use crate::errors::NyxResult;
+use crate::errors::{NyxError, NyxResult};
 use crate::patterns::Severity;
 use console::style;
 use serde::{Deserialize, Serialize};
@@ -17,12 +17,71 @@ pub enum AnalysisMode {
     Taint,
 }
 
+/// A user-supplied tree-sitter rule, parsed from the `[[scanner.rules]]`
+/// array in `nyx.local` so teams can ship project-specific security
+/// queries without forking the crate.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserRule {
+    /// Language this rule applies to (matches the `lang_slug` used by `patterns::load`).
+    pub lang: String,
+    /// Unique identifier (namespaced by the user, e.g. `acme_no_raw_sql`).
+    pub id: String,
+    /// Human-readable explanation, surfaced the same way built-in patterns are.
+    pub description: String,
+    /// tree-sitter query string, validated against the language grammar at load time.
+    pub query: String,
+    /// Rough severity bucket.
+    pub severity: Severity,
+}
+
+/// Which side of a taint flow a [`UserLabelRule`] marks its matched calls as
+/// — mirrors `labels::DataLabel`'s three variants, minus the `Cap` payload
+/// (that's `UserLabelRule::caps`, parsed separately since `Cap` isn't a
+/// `serde`-friendly shape to spell out per-flag in TOML/YAML).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LabelKind {
+    Source,
+    Sanitizer,
+    Sink,
+}
+
+/// A user-supplied taint-label rule, parsed alongside [`UserRule`] from the
+/// same rule files, so teams can ship org-specific source/sink/sanitizer
+/// definitions (see `labels::LabelRule`) without forking the crate.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct UserLabelRule {
+    /// Language this rule applies to (matches the `lang_slug` used by `labels::classify`).
+    pub lang: String,
+    /// Call-site text matchers, same suffix/prefix convention as a built-in
+    /// `LabelRule` (e.g. `"sanitize_"` as a prefix, `"execute"` as a suffix).
+    pub matchers: Vec<String>,
+    /// Source, Sanitizer, or Sink.
+    pub kind: LabelKind,
+    /// Capability tag names, OR'd together via `labels::Cap::intern`. One of
+    /// the seven built-in names (e.g. `"SHELL_ESCAPE"`) resolves to its fixed
+    /// bit; anything else mints a fresh runtime bit the first time it's seen
+    /// (stable for the rest of the process), so a project can name its own
+    /// capability classes without patching `labels::Cap`. Only running out
+    /// of bits (64 distinct tags, process-wide) is a load-time error.
+    #[serde(default)]
+    pub caps: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(default)]
 pub struct ScannerConfig {
     /// The analysis mode to use.
     pub mode: AnalysisMode,
 
+    /// User-defined tree-sitter patterns, merged into `patterns::load(lang)`
+    /// alongside the built-ins.
+    pub rules: Vec<UserRule>,
+
+    /// User-defined taint-label rules, installed into `labels::classify`
+    /// alongside the built-in per-language `LabelRule` tables.
+    pub label_rules: Vec<UserLabelRule>,
+
     /// The minimum severity level to output
     pub min_severity: Severity,
 
@@ -38,6 +97,15 @@ pub struct ScannerConfig {
     /// Excluded files
     pub excluded_files: Vec<String>,
 
+    /// File extensions to un-exclude. Entries here are subtracted from the
+    /// accumulated `excluded_extensions` list during a config merge, so a
+    /// project-local layer can re-enable scanning of an extension an outer
+    /// layer excluded — `merge_configs` alone can only ever union the lists.
+    pub excluded_extensions_remove: Vec<String>,
+
+    /// Same idea as `excluded_extensions_remove`, but for `excluded_directories`.
+    pub excluded_directories_remove: Vec<String>,
+
     /// Whether to respect the global ignore file or not.
     pub read_global_ignore: bool,
 
@@ -60,6 +128,8 @@ impl Default for ScannerConfig {
     fn default() -> Self {
         Self {
             mode: AnalysisMode::Full,
+            rules: Vec::new(),
+            label_rules: Vec::new(),
             min_severity: Severity::Low,
             max_file_size_mb: None,
             excluded_extensions: vec![
@@ -81,6 +151,8 @@ impl Default for ScannerConfig {
             .map(str::to_owned)
             .collect(),
             excluded_files: vec![].into_iter().map(str::to_owned).collect(),
+            excluded_extensions_remove: Vec::new(),
+            excluded_directories_remove: Vec::new(),
             read_global_ignore: false,
             read_vcsignore: true,
             require_git_to_read_vcsignore: true,
@@ -198,6 +270,12 @@ pub struct Config {
     pub database: DatabaseConfig,
     pub output: OutputConfig,
     pub performance: PerformanceConfig,
+
+    /// Other config files to merge in before this file's own settings,
+    /// resolved relative to this file's own directory. Only consulted by
+    /// [`Config::layered_for_path`]'s project-local discovery — the global
+    /// `nyx.local` loaded by [`Config::load`] doesn't recurse into includes.
+    pub include: Vec<String>,
 }
 
 impl Config {
@@ -236,10 +314,207 @@ impl Config {
             );
         }
 
+        let rules_dir = config_dir.join("rules");
+        if rules_dir.is_dir() {
+            let loaded = load_rule_files(&rules_dir)?;
+            if !loaded.rules.is_empty() || !loaded.label_rules.is_empty() {
+                println!(
+                    "{}: Loaded {} custom rule(s) ({} label rule(s)) from: {}\n",
+                    style("note").green().bold(),
+                    loaded.rules.len(),
+                    loaded.label_rules.len(),
+                    style(rules_dir.display()).underlined().white().bold()
+                );
+            }
+            config.scanner.rules.extend(loaded.rules);
+            config.scanner.label_rules.extend(loaded.label_rules);
+        }
+
+        for rule in &config.scanner.rules {
+            crate::patterns::validate_user_rule(rule)?;
+        }
+        crate::labels::install_user_rules(&config.scanner.label_rules)?;
+
+        Ok(config)
+    }
+
+    /// Layer project-local config on top of `self`, Mercurial-style: walk
+    /// upward from `start_path` to the filesystem root collecting every
+    /// ancestor directory that has a [`PROJECT_CONFIG_FILENAME`], then merge
+    /// them root-first so the layer closest to `start_path` wins.
+    ///
+    /// Each layer may itself pull in shared config via `include = [...]`
+    /// (paths resolved relative to the including file, recursively, guarded
+    /// against cycles and capped at [`MAX_INCLUDE_DEPTH`]), and may subtract
+    /// from the inherited exclusion lists with `excluded_extensions_remove`
+    /// / `excluded_directories_remove`, since a plain merge can only ever
+    /// union them.
+    pub fn layered_for_path(&self, start_path: &Path) -> NyxResult<Config> {
+        let start_dir = if start_path.is_dir() {
+            start_path.to_path_buf()
+        } else {
+            start_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| start_path.to_path_buf())
+        };
+
+        let mut layers: Vec<std::path::PathBuf> = start_dir
+            .ancestors()
+            .map(|dir| dir.join(PROJECT_CONFIG_FILENAME))
+            .filter(|p| p.is_file())
+            .collect();
+        layers.reverse(); // root-first: the layer nearest `start_path` wins
+
+        let mut config = self.clone();
+        for layer in layers {
+            let mut chain = Vec::new();
+            let layer_config = load_config_file(&layer, &mut chain, 0)?;
+            config = merge_configs(config, layer_config);
+        }
+
+        for rule in &config.scanner.rules {
+            crate::patterns::validate_user_rule(rule)?;
+        }
+        crate::labels::install_user_rules(&config.scanner.label_rules)?;
+
         Ok(config)
     }
 }
 
+/// Filename searched for when walking upward from a scanned path to collect
+/// project-local config layers (see [`Config::layered_for_path`]). Distinct
+/// from the `nyx.conf`/`nyx.local` pair in `config_dir`, which this layers
+/// on top of — `.nyxrc` is meant to be checked into a project and shared
+/// between contributors, not machine-local.
+const PROJECT_CONFIG_FILENAME: &str = ".nyxrc";
+
+/// Hard cap on `include = [...]` recursion depth, guarding against runaway
+/// chains even in cases the cycle check below wouldn't catch (e.g. a very
+/// long, strictly acyclic include chain).
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// Load a single config file, resolving its `include = [...]` directive
+/// before applying its own settings — an included file is merged in first,
+/// so the including file's own keys always win.
+///
+/// `chain` tracks the files visited along the *current* include path, not
+/// globally, so the same shared file can be included from two unrelated
+/// branches without tripping the cycle check; only an include that loops
+/// back to one of its own ancestors is rejected.
+fn load_config_file(
+    path: &Path,
+    chain: &mut Vec<std::path::PathBuf>,
+    depth: usize,
+) -> NyxResult<Config> {
+    if depth > MAX_INCLUDE_DEPTH {
+        return Err(NyxError::Msg(format!(
+            "include depth exceeded {MAX_INCLUDE_DEPTH} while loading {}",
+            path.display()
+        )));
+    }
+
+    let canonical = path.canonicalize()?;
+    if chain.contains(&canonical) {
+        return Err(NyxError::Msg(format!(
+            "include cycle detected at {}",
+            canonical.display()
+        )));
+    }
+
+    let content = fs::read_to_string(&canonical)?;
+    let file_config: Config = toml::from_str(&content)?;
+
+    if file_config.include.is_empty() {
+        return Ok(file_config);
+    }
+
+    chain.push(canonical.clone());
+    let base_dir = canonical.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut merged = Config::default();
+    for include in &file_config.include {
+        let include_path = base_dir.join(include);
+        let included = load_config_file(&include_path, chain, depth + 1)?;
+        merged = merge_configs(merged, included);
+    }
+    chain.pop();
+
+    Ok(merge_configs(merged, file_config))
+}
+
+/// Both rule kinds collected by [`load_rule_files`] from one `rules/` directory.
+#[derive(Default)]
+struct LoadedRules {
+    rules: Vec<UserRule>,
+    label_rules: Vec<UserLabelRule>,
+}
+
+/// Parse every `.toml`/`.yaml`/`.yml` file directly inside `rules_dir` into
+/// a flat [`LoadedRules`].
+///
+/// TOML files use the same `[[rule]]` array-of-tables shape as
+/// `[[scanner.rules]]` in `nyx.local`, plus an `[[label_rule]]` array for
+/// `UserLabelRule`s. YAML files try the equivalent `{ rules: [...],
+/// label_rules: [...] }` mapping first, falling back to the older bare
+/// top-level sequence of plain `UserRule`s (YAML doesn't need a wrapping key
+/// for a top-level list, which is all this subsystem supported before
+/// label rules existed). Either way, this just collects rules for the
+/// caller to merge and validate — it doesn't compile queries or resolve
+/// capability names itself, so one malformed file reports a clear parse
+/// error instead of a silent skip.
+fn load_rule_files(rules_dir: &Path) -> NyxResult<LoadedRules> {
+    #[derive(Deserialize)]
+    struct TomlRuleFile {
+        #[serde(default)]
+        rule: Vec<UserRule>,
+        #[serde(default)]
+        label_rule: Vec<UserLabelRule>,
+    }
+
+    #[derive(Deserialize)]
+    struct YamlRuleFile {
+        #[serde(default)]
+        rules: Vec<UserRule>,
+        #[serde(default)]
+        label_rules: Vec<UserLabelRule>,
+    }
+
+    let mut loaded = LoadedRules::default();
+    let mut entries: Vec<_> = fs::read_dir(rules_dir)?.filter_map(|e| e.ok()).collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let path = entry.path();
+        let Some(ext) = path.extension().and_then(|e| e.to_str()) else {
+            continue;
+        };
+
+        let content = fs::read_to_string(&path)?;
+        match ext.to_ascii_lowercase().as_str() {
+            "toml" => {
+                let file: TomlRuleFile = toml::from_str(&content)?;
+                loaded.rules.extend(file.rule);
+                loaded.label_rules.extend(file.label_rule);
+            }
+            "yaml" | "yml" => {
+                if let Ok(file) = serde_yaml::from_str::<YamlRuleFile>(&content) {
+                    loaded.rules.extend(file.rules);
+                    loaded.label_rules.extend(file.label_rules);
+                } else {
+                    let file: Vec<UserRule> = serde_yaml::from_str(&content).map_err(|e| {
+                        NyxError::Msg(format!("{}: invalid rule file: {e}", path.display()))
+                    })?;
+                    loaded.rules.extend(file);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(loaded)
+}
+
 fn create_example_config(config_dir: &Path) -> NyxResult<()> {
     let example_path = config_dir.join("nyx.conf");
     if !example_path.exists() {
@@ -249,19 +524,68 @@ fn create_example_config(config_dir: &Path) -> NyxResult<()> {
     Ok(())
 }
 
+/// Overwrites `*slot` with `user_val` only when `user_val` differs from
+/// `base` (the field's struct default) — i.e. only when this layer's raw
+/// TOML plausibly set it. `#[serde(default)]` means an unset field in a
+/// layer's file deserializes to its type default indistinguishably from one
+/// explicitly set to that same value, so this can't tell "unset" from
+/// "explicitly set back to the default" — but it's what stops a layer that
+/// never mentions a field from silently resetting whatever an earlier layer
+/// configured, which a bare overwrite did.
+fn overlay<T: PartialEq>(slot: &mut T, user_val: T, base: &T) {
+    if user_val != *base {
+        *slot = user_val;
+    }
+}
+
 /// Merge user config into default config, preserving defaults where the user didn't
 /// supply new exclusions and overriding everything else.
 fn merge_configs(mut default: Config, user: Config) -> Config {
     // --- ScannerConfig ---
-    default.scanner.mode = user.scanner.mode;
-    default.scanner.min_severity = user.scanner.min_severity;
-    default.scanner.max_file_size_mb = user.scanner.max_file_size_mb;
-    default.scanner.read_global_ignore = user.scanner.read_global_ignore;
-    default.scanner.read_vcsignore = user.scanner.read_vcsignore;
-    default.scanner.require_git_to_read_vcsignore = user.scanner.require_git_to_read_vcsignore;
-    default.scanner.one_file_system = user.scanner.one_file_system;
-    default.scanner.follow_symlinks = user.scanner.follow_symlinks;
-    default.scanner.scan_hidden_files = user.scanner.scan_hidden_files;
+    let scanner_base = ScannerConfig::default();
+    overlay(&mut default.scanner.mode, user.scanner.mode, &scanner_base.mode);
+    overlay(
+        &mut default.scanner.min_severity,
+        user.scanner.min_severity,
+        &scanner_base.min_severity,
+    );
+    overlay(
+        &mut default.scanner.max_file_size_mb,
+        user.scanner.max_file_size_mb,
+        &scanner_base.max_file_size_mb,
+    );
+    overlay(
+        &mut default.scanner.read_global_ignore,
+        user.scanner.read_global_ignore,
+        &scanner_base.read_global_ignore,
+    );
+    overlay(
+        &mut default.scanner.read_vcsignore,
+        user.scanner.read_vcsignore,
+        &scanner_base.read_vcsignore,
+    );
+    overlay(
+        &mut default.scanner.require_git_to_read_vcsignore,
+        user.scanner.require_git_to_read_vcsignore,
+        &scanner_base.require_git_to_read_vcsignore,
+    );
+    overlay(
+        &mut default.scanner.one_file_system,
+        user.scanner.one_file_system,
+        &scanner_base.one_file_system,
+    );
+    overlay(
+        &mut default.scanner.follow_symlinks,
+        user.scanner.follow_symlinks,
+        &scanner_base.follow_symlinks,
+    );
+    overlay(
+        &mut default.scanner.scan_hidden_files,
+        user.scanner.scan_hidden_files,
+        &scanner_base.scan_hidden_files,
+    );
+    default.scanner.rules.extend(user.scanner.rules);
+    default.scanner.label_rules.extend(user.scanner.label_rules);
 
     // Merge exclusion lists (default ⊔ user), then sort & dedupe
     default
@@ -277,27 +601,98 @@ fn merge_configs(mut default: Config, user: Config) -> Config {
     default.scanner.excluded_directories.sort_unstable();
     default.scanner.excluded_directories.dedup();
 
+    // Apply this layer's "unset" directives *after* the union above, so a
+    // layer can remove an exclusion it just inherited (or one from further
+    // up the chain) instead of only ever adding to the lists.
+    if !user.scanner.excluded_extensions_remove.is_empty() {
+        default
+            .scanner
+            .excluded_extensions
+            .retain(|e| !user.scanner.excluded_extensions_remove.contains(e));
+    }
+    if !user.scanner.excluded_directories_remove.is_empty() {
+        default
+            .scanner
+            .excluded_directories
+            .retain(|e| !user.scanner.excluded_directories_remove.contains(e));
+    }
+
     // --- DatabaseConfig ---
-    default.database.path = user.database.path;
-    default.database.auto_cleanup_days = user.database.auto_cleanup_days;
-    default.database.max_db_size_mb = user.database.max_db_size_mb;
-    default.database.vacuum_on_startup = user.database.vacuum_on_startup;
+    let database_base = DatabaseConfig::default();
+    overlay(&mut default.database.path, user.database.path, &database_base.path);
+    overlay(
+        &mut default.database.auto_cleanup_days,
+        user.database.auto_cleanup_days,
+        &database_base.auto_cleanup_days,
+    );
+    overlay(
+        &mut default.database.max_db_size_mb,
+        user.database.max_db_size_mb,
+        &database_base.max_db_size_mb,
+    );
+    overlay(
+        &mut default.database.vacuum_on_startup,
+        user.database.vacuum_on_startup,
+        &database_base.vacuum_on_startup,
+    );
 
     // --- OutputConfig ---
-    default.output.default_format = user.output.default_format;
-    default.output.quiet = user.output.quiet;
-    default.output.max_results = user.output.max_results;
+    let output_base = OutputConfig::default();
+    overlay(
+        &mut default.output.default_format,
+        user.output.default_format,
+        &output_base.default_format,
+    );
+    overlay(&mut default.output.quiet, user.output.quiet, &output_base.quiet);
+    overlay(
+        &mut default.output.max_results,
+        user.output.max_results,
+        &output_base.max_results,
+    );
 
     // --- PerformanceConfig ---
-    default.performance.max_depth = user.performance.max_depth;
-    default.performance.min_depth = user.performance.min_depth;
-    default.performance.prune = user.performance.prune;
-    default.performance.worker_threads = user.performance.worker_threads;
-    default.performance.batch_size = user.performance.batch_size;
-    default.performance.channel_multiplier = user.performance.channel_multiplier;
-    default.performance.rayon_thread_stack_size = user.performance.rayon_thread_stack_size;
-    default.performance.scan_timeout_secs = user.performance.scan_timeout_secs;
-    default.performance.memory_limit_mb = user.performance.memory_limit_mb;
+    let performance_base = PerformanceConfig::default();
+    overlay(
+        &mut default.performance.max_depth,
+        user.performance.max_depth,
+        &performance_base.max_depth,
+    );
+    overlay(
+        &mut default.performance.min_depth,
+        user.performance.min_depth,
+        &performance_base.min_depth,
+    );
+    overlay(&mut default.performance.prune, user.performance.prune, &performance_base.prune);
+    overlay(
+        &mut default.performance.worker_threads,
+        user.performance.worker_threads,
+        &performance_base.worker_threads,
+    );
+    overlay(
+        &mut default.performance.batch_size,
+        user.performance.batch_size,
+        &performance_base.batch_size,
+    );
+    overlay(
+        &mut default.performance.channel_multiplier,
+        user.performance.channel_multiplier,
+        &performance_base.channel_multiplier,
+    );
+    overlay(
+        &mut default.performance.rayon_thread_stack_size,
+        user.performance.rayon_thread_stack_size,
+        &performance_base.rayon_thread_stack_size,
+    );
+    overlay(
+        &mut default.performance.scan_timeout_secs,
+        user.performance.scan_timeout_secs,
+        &performance_base.scan_timeout_secs,
+    );
+    overlay(
+        &mut default.performance.memory_limit_mb,
+        user.performance.memory_limit_mb,
+        &performance_base.memory_limit_mb,
+    );
 
     default
 }
@@ -343,3 +738,39 @@ fn load_creates_example_and_reads_user_overrides() {
 
     assert!(!cfg.scanner.follow_symlinks);
 }
+
+#[test]
+fn layered_for_path_does_not_let_an_inner_layer_reset_an_outer_setting() {
+    let root = tempfile::tempdir().unwrap();
+    let outer_dir = root.path().join("proj");
+    let inner_dir = outer_dir.join("sub");
+    fs::create_dir_all(&inner_dir).unwrap();
+
+    // Outer layer sets `min_severity`; inner layer only sets an unrelated
+    // field. The outer setting must survive instead of being wiped back to
+    // `ScannerConfig::default()`'s `min_severity` by the inner layer's
+    // `#[serde(default)]`-filled value.
+    fs::write(
+        outer_dir.join(".nyxrc"),
+        r#"
+        [scanner]
+        min_severity = "High"
+    "#,
+    )
+    .unwrap();
+    fs::write(
+        inner_dir.join(".nyxrc"),
+        r#"
+        [scanner]
+        one_file_system = true
+    "#,
+    )
+    .unwrap();
+
+    let cfg = Config::default()
+        .layered_for_path(&inner_dir)
+        .expect("layered_for_path should succeed");
+
+    assert_eq!(cfg.scanner.min_severity, Severity::High);
+    assert!(cfg.scanner.one_file_system);
+}
@@ -2,6 +2,7 @@ pub mod config;
 pub(crate) mod ext;
 pub mod project;
 pub(crate) mod query_cache;
+pub mod query_lang;
 
 pub use config::Config;
 // Re-export commonly used functions for convenience
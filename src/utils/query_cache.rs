@@ -1,8 +1,11 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, LazyLock, RwLock};
 use tree_sitter::{Language, Query};
 
 use crate::patterns::{self, Pattern};
+use crate::utils::config::UserRule;
 
 #[derive(Clone)]
 pub struct CompiledQuery {
@@ -11,29 +14,72 @@ pub struct CompiledQuery {
 }
 
 type QuerySet = Arc<Vec<CompiledQuery>>;
-static CACHE: LazyLock<RwLock<HashMap<&'static str, QuerySet>>> =
+
+/// Keyed on `(lang, hash-of-relevant-user-rules)` rather than just `lang` so
+/// that two projects with different `[[scanner.rules]]` don't clobber each
+/// other's compiled query set in this process-lifetime cache.
+static CACHE: LazyLock<RwLock<HashMap<(&'static str, u64), QuerySet>>> =
     LazyLock::new(|| RwLock::new(HashMap::new()));
 
-/// Return **one shared Arc** to the per-language query set.
-/// Cloning the `Arc` is O(1) and the underlying Vec lives for the
-/// lifetime of the process.
-pub fn for_lang(lang: &'static str, ts_lang: Language) -> std::sync::Arc<Vec<CompiledQuery>> {
+fn hash_rules<'a>(rules: impl Iterator<Item = &'a UserRule>) -> u64 {
+    let mut h = std::collections::hash_map::DefaultHasher::new();
+    for r in rules {
+        r.id.hash(&mut h);
+        r.description.hash(&mut h);
+        r.query.hash(&mut h);
+        r.severity.as_db_str().hash(&mut h);
+    }
+    h.finish()
+}
+
+/// Return **one shared Arc** to the per-language query set, merging the
+/// built-in `PATTERNS` table with any `[[scanner.rules]]` the user supplied
+/// for this language. Cloning the `Arc` is O(1) and the underlying Vec lives
+/// for the lifetime of the process.
+pub fn for_lang(
+    lang: &'static str,
+    ts_lang: Language,
+    user_rules: &[UserRule],
+) -> std::sync::Arc<Vec<CompiledQuery>> {
+    let relevant: Vec<&UserRule> = user_rules
+        .iter()
+        .filter(|r| r.lang.eq_ignore_ascii_case(lang))
+        .collect();
+    let key = (lang, hash_rules(relevant.iter().copied()));
+
     // fast path
-    if let Some(v) = CACHE.read().unwrap().get(lang) {
+    if let Some(v) = CACHE.read().unwrap().get(&key) {
         return v.clone();
     }
 
-    // slow path — compile
-    let patterns = patterns::load(lang);
+    // slow path — compile built-ins + user rules
+    let mut patterns = patterns::load(lang);
+    for r in &relevant {
+        patterns.push(Pattern {
+            id: Cow::Owned(r.id.clone()),
+            description: Cow::Owned(r.description.clone()),
+            query: Cow::Owned(r.query.clone()),
+            severity: r.severity,
+            cwe: None,
+            owasp: None,
+            fix: None,
+        });
+    }
+
+    // User rules are already validated (query compiles against the
+    // language grammar) when `Config::load` reads them, so a compile
+    // failure here can only come from a built-in pattern — keep it a
+    // warn-and-skip rather than a hard error so one broken built-in
+    // doesn't take every other rule down with it.
     let compiled: Vec<_> = patterns
         .into_iter()
-        .filter_map(|p| match Query::new(&ts_lang, p.query) {
+        .filter_map(|p| match Query::new(&ts_lang, &p.query) {
             Ok(q) => Some(CompiledQuery {
                 meta: p,
                 query: std::sync::Arc::new(q),
             }),
             Err(e) => {
-                tracing::warn!(lang, id = p.id, "query compile error: {e}");
+                tracing::warn!(lang, id = %p.id, "query compile error: {e}");
                 None
             }
         })
@@ -42,5 +88,5 @@ pub fn for_lang(lang: &'static str, ts_lang: Language) -> std::sync::Arc<Vec<Com
     let compiled = std::sync::Arc::new(compiled);
 
     let mut w = CACHE.write().unwrap();
-    w.entry(lang).or_insert_with(|| compiled.clone()).clone()
+    w.entry(key).or_insert_with(|| compiled.clone()).clone()
 }
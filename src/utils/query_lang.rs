@@ -0,0 +1,412 @@
+//! A small query language for `nyx query "<expr>"`: `field:value` pairs,
+//! quoted strings, `AND`/`OR`/`NOT`, parentheses, and juxtaposition as an
+//! implicit `AND` (e.g. `severity:high rule:sql_injection` == the same
+//! joined with `AND`). Parses to an [`Expr`] tree, which callers compile to
+//! a parameterized SQL `WHERE` clause ([`compile`]) or evaluate directly
+//! against an in-memory row ([`eval`]) — never by string-interpolating user
+//! input into SQL.
+
+use crate::errors::{NyxError, NyxResult};
+use crate::patterns::Severity;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+    Field(String, String),
+    FreeText(String),
+}
+
+fn read_word(chars: &mut Peekable<Chars>) -> NyxResult<String> {
+    if chars.peek() == Some(&'"') {
+        chars.next();
+        let mut s = String::new();
+        loop {
+            match chars.next() {
+                Some('"') => return Ok(s),
+                Some('\\') => {
+                    if let Some(escaped) = chars.next() {
+                        s.push(escaped);
+                    }
+                }
+                Some(c) => s.push(c),
+                None => return Err(NyxError::from("unterminated quoted string in query")),
+            }
+        }
+    }
+
+    let mut s = String::new();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() || c == '(' || c == ')' || c == ':' {
+            break;
+        }
+        s.push(c);
+        chars.next();
+    }
+    Ok(s)
+}
+
+fn lex(input: &str) -> NyxResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c == '(' {
+            chars.next();
+            tokens.push(Token::LParen);
+            continue;
+        }
+        if c == ')' {
+            chars.next();
+            tokens.push(Token::RParen);
+            continue;
+        }
+
+        let word = read_word(&mut chars)?;
+        if chars.peek() == Some(&':') {
+            chars.next(); // consume ':'
+            let value = read_word(&mut chars)?;
+            tokens.push(Token::Field(word, value));
+            continue;
+        }
+
+        match word.to_ascii_uppercase().as_str() {
+            "AND" => tokens.push(Token::And),
+            "OR" => tokens.push(Token::Or),
+            "NOT" => tokens.push(Token::Not),
+            _ => tokens.push(Token::FreeText(word)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Boolean AST for a parsed query expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Field { name: String, value: String },
+    FreeText(String),
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Option<&Token> {
+        let t = self.tokens.get(self.pos);
+        self.pos += 1;
+        t
+    }
+
+    // lowest precedence: OR
+    fn parse_or(&mut self) -> NyxResult<Expr> {
+        let mut lhs = self.parse_and()?;
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.pos += 1;
+            let rhs = self.parse_and()?;
+            lhs = Expr::Or(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    // AND, with juxtaposition of two atoms treated as an implicit AND
+    fn parse_and(&mut self) -> NyxResult<Expr> {
+        let mut lhs = self.parse_unary()?;
+        loop {
+            match self.peek() {
+                Some(Token::Or) | Some(Token::RParen) | None => break,
+                Some(Token::And) => {
+                    self.pos += 1;
+                }
+                _ => {} // implicit AND — don't consume, just parse the next term
+            }
+            let rhs = self.parse_unary()?;
+            lhs = Expr::And(Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> NyxResult<Expr> {
+        if matches!(self.peek(), Some(Token::Not)) {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_atom()
+    }
+
+    fn parse_atom(&mut self) -> NyxResult<Expr> {
+        match self.bump().cloned() {
+            Some(Token::LParen) => {
+                let inner = self.parse_or()?;
+                match self.bump() {
+                    Some(Token::RParen) => Ok(inner),
+                    _ => Err(NyxError::from("expected closing ')' in query")),
+                }
+            }
+            Some(Token::Field(name, value)) => Ok(Expr::Field { name, value }),
+            Some(Token::FreeText(text)) => Ok(Expr::FreeText(text)),
+            other => Err(NyxError::from(format!(
+                "unexpected token in query: {other:?}"
+            ))),
+        }
+    }
+}
+
+/// Parse a full query expression, e.g. `severity:high (rule:sql* OR lang:rust) NOT path:vendor/*`.
+pub fn parse(input: &str) -> NyxResult<Expr> {
+    let tokens = lex(input)?;
+    if tokens.is_empty() {
+        return Err(NyxError::from("empty query expression"));
+    }
+
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+    };
+    let expr = parser.parse_or()?;
+    if parser.pos != tokens.len() {
+        return Err(NyxError::from("trailing tokens after query expression"));
+    }
+    Ok(expr)
+}
+
+// ---------------------------------------------------------------------------
+// SQL compilation (SqliteStore)
+// ---------------------------------------------------------------------------
+
+/// A value bound into the SQL generated by [`compile`] — kept as a small
+/// owned enum rather than a raw `&dyn ToSql` so this module doesn't need to
+/// depend on rusqlite directly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    Text(String),
+}
+
+/// Compile `expr` to a parameterized SQL boolean expression (over the
+/// `issues JOIN files` view `query_issues` already uses) plus its bound
+/// parameters, in left-to-right `?` order. Never interpolates `value`
+/// directly into the SQL string.
+pub fn compile(expr: &Expr) -> NyxResult<(String, Vec<SqlValue>)> {
+    match expr {
+        Expr::And(l, r) => {
+            let (ls, mut lp) = compile(l)?;
+            let (rs, rp) = compile(r)?;
+            lp.extend(rp);
+            Ok((format!("({ls} AND {rs})"), lp))
+        }
+        Expr::Or(l, r) => {
+            let (ls, mut lp) = compile(l)?;
+            let (rs, rp) = compile(r)?;
+            lp.extend(rp);
+            Ok((format!("({ls} OR {rs})"), lp))
+        }
+        Expr::Not(e) => {
+            let (s, p) = compile(e)?;
+            Ok((format!("NOT ({s})"), p))
+        }
+        Expr::Field { name, value } => compile_field(name, value),
+        // No dedicated "summary text" column on the issues view this DSL
+        // runs over, so free text matches the closest analogue: rule id.
+        Expr::FreeText(text) => Ok((
+            "issues.rule_id LIKE ?".into(),
+            vec![SqlValue::Text(format!("%{text}%"))],
+        )),
+    }
+}
+
+fn compile_field(name: &str, value: &str) -> NyxResult<(String, Vec<SqlValue>)> {
+    match name.to_ascii_lowercase().as_str() {
+        "severity" => {
+            let sev = Severity::from_str(value)
+                .map_err(|_| NyxError::from(format!("unknown severity in query: {value}")))?;
+            Ok((
+                "issues.severity = ?".into(),
+                vec![SqlValue::Text(sev.as_db_str().to_owned())],
+            ))
+        }
+        "rule" => Ok((
+            "issues.rule_id = ?".into(),
+            vec![SqlValue::Text(value.to_owned())],
+        )),
+        "path" => Ok((
+            "files.path GLOB ?".into(),
+            vec![SqlValue::Text(value.to_owned())],
+        )),
+        "lang" => Ok((
+            "files.path GLOB ?".into(),
+            vec![SqlValue::Text(format!("*.{value}"))],
+        )),
+        "cwe" => rule_ids_filter(&crate::patterns::rule_ids_for_cwe(value)),
+        "owasp" => rule_ids_filter(&crate::patterns::rule_ids_for_owasp(value)),
+        other => Err(NyxError::from(format!("unknown query field: {other}"))),
+    }
+}
+
+/// `rule_id IN (?, ...)` over a taxonomy lookup's member rule ids — `FALSE`
+/// (via an always-empty `IN ()`) when the CWE/OWASP id names no built-in
+/// rule, rather than an error, so e.g. a typo'd `cwe:` value just matches
+/// nothing instead of failing the whole query.
+fn rule_ids_filter(ids: &[&'static str]) -> NyxResult<(String, Vec<SqlValue>)> {
+    if ids.is_empty() {
+        return Ok(("0".into(), vec![]));
+    }
+    let placeholders = vec!["?"; ids.len()].join(", ");
+    Ok((
+        format!("issues.rule_id IN ({placeholders})"),
+        ids.iter().map(|id| SqlValue::Text((*id).to_owned())).collect(),
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Direct evaluation (MemoryStore)
+// ---------------------------------------------------------------------------
+
+/// The subset of an issue row the in-memory store can evaluate an [`Expr`]
+/// against, without going through SQL.
+pub struct IssueRecord<'a> {
+    pub path: &'a str,
+    pub rule_id: &'a str,
+    pub severity: Severity,
+}
+
+pub fn eval(expr: &Expr, row: &IssueRecord) -> NyxResult<bool> {
+    Ok(match expr {
+        Expr::And(l, r) => eval(l, row)? && eval(r, row)?,
+        Expr::Or(l, r) => eval(l, row)? || eval(r, row)?,
+        Expr::Not(e) => !eval(e, row)?,
+        Expr::Field { name, value } => eval_field(name, value, row)?,
+        Expr::FreeText(text) => row
+            .rule_id
+            .to_ascii_lowercase()
+            .contains(&text.to_ascii_lowercase()),
+    })
+}
+
+fn eval_field(name: &str, value: &str, row: &IssueRecord) -> NyxResult<bool> {
+    Ok(match name.to_ascii_lowercase().as_str() {
+        "severity" => {
+            let sev = Severity::from_str(value)
+                .map_err(|_| NyxError::from(format!("unknown severity in query: {value}")))?;
+            row.severity == sev
+        }
+        "rule" => row.rule_id == value,
+        "path" => glob_match(row.path, value),
+        "lang" => glob_match(row.path, &format!("*.{value}")),
+        "cwe" => crate::patterns::describe(row.rule_id).is_some_and(|p| p.cwe == Some(value)),
+        "owasp" => crate::patterns::describe(row.rule_id).is_some_and(|p| p.owasp == Some(value)),
+        other => return Err(NyxError::from(format!("unknown query field: {other}"))),
+    })
+}
+
+/// Tiny glob matcher (`*` = any run of characters, `?` = one character) —
+/// enough for `path:`/`lang:` filters without depending on a glob crate.
+/// Mirrors what SQLite's own `GLOB` does for the SQL-backed store.
+fn glob_match(value: &str, pattern: &str) -> bool {
+    fn rec(v: &[u8], p: &[u8]) -> bool {
+        match (v.first(), p.first()) {
+            (_, Some(b'*')) => rec(v, &p[1..]) || (!v.is_empty() && rec(&v[1..], p)),
+            (Some(_), Some(b'?')) => rec(&v[1..], &p[1..]),
+            (Some(a), Some(b)) if a == b => rec(&v[1..], &p[1..]),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+    rec(value.as_bytes(), pattern.as_bytes())
+}
+
+#[test]
+fn parses_field_and_implicit_and() {
+    let expr = parse("severity:high rule:sql_injection").unwrap();
+    assert_eq!(
+        expr,
+        Expr::And(
+            Box::new(Expr::Field {
+                name: "severity".into(),
+                value: "high".into()
+            }),
+            Box::new(Expr::Field {
+                name: "rule".into(),
+                value: "sql_injection".into()
+            }),
+        )
+    );
+}
+
+#[test]
+fn parses_or_not_and_parens() {
+    let expr = parse(r#"(lang:rust OR lang:go) NOT path:vendor/*"#).unwrap();
+    assert_eq!(
+        expr,
+        Expr::And(
+            Box::new(Expr::Or(
+                Box::new(Expr::Field {
+                    name: "lang".into(),
+                    value: "rust".into()
+                }),
+                Box::new(Expr::Field {
+                    name: "lang".into(),
+                    value: "go".into()
+                }),
+            )),
+            Box::new(Expr::Not(Box::new(Expr::Field {
+                name: "path".into(),
+                value: "vendor/*".into()
+            }))),
+        )
+    );
+}
+
+#[test]
+fn rejects_unbalanced_parens() {
+    assert!(parse("(severity:high").is_err());
+}
+
+#[test]
+fn compiles_field_to_parameterized_placeholders() {
+    let expr = parse("severity:high").unwrap();
+    let (sql, params) = compile(&expr).unwrap();
+    assert_eq!(sql, "issues.severity = ?");
+    assert_eq!(params, vec![SqlValue::Text("HIGH".into())]);
+}
+
+#[test]
+fn compile_rejects_unknown_field() {
+    let expr = parse("bogus:1").unwrap();
+    assert!(compile(&expr).is_err());
+}
+
+#[test]
+fn eval_matches_memory_row_directly() {
+    let expr = parse("severity:high rule:sql_injection").unwrap();
+    let row = IssueRecord {
+        path: "src/main.rs",
+        rule_id: "sql_injection",
+        severity: Severity::High,
+    };
+    assert!(eval(&expr, &row).unwrap());
+
+    let other = IssueRecord {
+        path: "src/main.rs",
+        rule_id: "weak_hash_md5",
+        severity: Severity::High,
+    };
+    assert!(!eval(&expr, &other).unwrap());
+}